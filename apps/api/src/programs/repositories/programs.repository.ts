@@ -264,6 +264,20 @@ export class ProgramsRepository {
     return parseInt(result.rows[0].count, 10);
   }
 
+  async countActiveByUser(userId: string): Promise<number> {
+    const result = await this.databaseService.query<{ count: string }>(
+      `
+      SELECT COUNT(*) as count
+      FROM programs
+      WHERE user_id = $1
+        AND status IN ('deployed', 'pending')
+        AND claimed_at IS NULL
+    `,
+      [userId],
+    );
+    return parseInt(result.rows[0].count, 10);
+  }
+
   async getTotalStats(): Promise<{
     totalPrograms: number;
     byStatus: Record<string, number>;
@@ -0,0 +1,28 @@
+import { IsIn, IsInt, IsString, Matches, Max, Min } from 'class-validator';
+
+export class DeployAttestationDto {
+  /**
+   * Wallet that will sign register_deployment or upgrade_hosted; must be
+   * linked to the caller's account
+   */
+  @IsString()
+  deployer: string;
+
+  /** Which instruction the attestation is for */
+  @IsIn(['register', 'upgrade'])
+  action: 'register' | 'upgrade';
+
+  /** Program being registered or upgraded */
+  @IsString()
+  programAddress: string;
+
+  /** Hex SHA-256 of the program bytes */
+  @IsString()
+  @Matches(/^[0-9a-f]{64}$/)
+  programHash: string;
+
+  @IsInt()
+  @Min(1)
+  @Max(10 * 1024 * 1024)
+  programSize: number;
+}
@@ -20,4 +20,8 @@ export class DeployProgramDto {
   @IsString()
   @IsOptional()
   programPath?: string;
+
+  @IsString()
+  @IsOptional()
+  programAddress?: string;
 }
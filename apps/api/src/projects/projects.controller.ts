@@ -17,11 +17,16 @@ import { ProjectsService } from './projects.service';
 import { CreateProjectDto } from './dto/create-project.dto';
 import { UpdateProjectDto } from './dto/update-project.dto';
 import { DeployProgramDto } from './dto/deploy-program.dto';
+import { DeployAttestationDto } from './dto/deploy-attestation.dto';
+import { AttestationService } from './services/attestation.service';
 
 @Controller('projects')
 @UseGuards(JwtAuthGuard)
 export class ProjectsController {
-  constructor(private projectsService: ProjectsService) {}
+  constructor(
+    private projectsService: ProjectsService,
+    private attestationService: AttestationService,
+  ) {}
 
   @Post()
   async createProject(
@@ -101,4 +106,18 @@ export class ProjectsController {
   ) {
     return this.projectsService.initiateDeployment(req.user.userId, deployDto);
   }
+
+  /**
+   * CLI Attestation Endpoint
+   * Signs the program, hash and size the CLI is about to register or upgrade
+   * to, which the program-deployer program checks on-chain. The deployer must
+   * be a wallet linked to the caller's account.
+   */
+  @Post('deploy/attestation')
+  async attestDeployment(
+    @Request() req,
+    @Body() attestationDto: DeployAttestationDto,
+  ) {
+    return this.attestationService.attest(req.user.userId, attestationDto);
+  }
 }
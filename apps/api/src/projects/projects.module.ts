@@ -2,13 +2,15 @@ import { Module } from '@nestjs/common';
 import { ProjectsController } from './projects.controller';
 import { ProjectsService } from './projects.service';
 import { ProjectsRepository } from './repositories/projects.repository';
+import { AttestationService } from './services/attestation.service';
 import { ProgramsModule } from '../programs/programs.module';
 import { DatabaseModule } from '../database/database.module';
+import { UsersModule } from '../users/users.module';
 
 @Module({
-  imports: [DatabaseModule, ProgramsModule],
+  imports: [DatabaseModule, ProgramsModule, UsersModule],
   controllers: [ProjectsController],
-  providers: [ProjectsService, ProjectsRepository],
+  providers: [ProjectsService, ProjectsRepository, AttestationService],
   exports: [ProjectsService, ProjectsRepository],
 })
 export class ProjectsModule {}
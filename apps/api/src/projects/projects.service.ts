@@ -178,11 +178,11 @@ export class ProjectsService {
       });
     }
 
-    // Create placeholder program record (no address yet since deployment hasn't happened)
-    // The CLI will update this with the actual address after deployment
+    // The CLI sends the address it is about to deploy to; older CLIs don't,
+    // so fall back to a placeholder
     const programRecord = await this.programsRepository.create({
       userId,
-      programAddress: 'pending', // Temporary placeholder
+      programAddress: deployDto.programAddress ?? 'pending',
       name: deployDto.programName,
       description: deployDto.description,
       cluster: deployDto.cluster,
@@ -0,0 +1,208 @@
+import {
+  BadRequestException,
+  ForbiddenException,
+  HttpException,
+  HttpStatus,
+  Injectable,
+  ServiceUnavailableException,
+} from '@nestjs/common';
+import {
+  address,
+  createKeyPairSignerFromBytes,
+  getAddressEncoder,
+  isAddress,
+  signBytes,
+} from 'gill';
+import { randomBytes } from 'crypto';
+import { DeployAttestationDto } from '../dto/deploy-attestation.dto';
+import { AuthMethodsRepository } from '../../users/repositories/auth-methods.repository';
+import { ProgramsRepository } from '../../programs/repositories/programs.repository';
+import { StorageService } from '../../storage/storage.service';
+
+/** Must match ATTESTATION_DOMAIN in the program-deployer program */
+const ATTESTATION_DOMAIN = Buffer.from('program-deployer:attestation:v1');
+
+/** Borsh discriminants of the program's AttestationAction */
+const ATTESTATION_ACTIONS = { register: 0, upgrade: 1 } as const;
+
+/** How long the CLI has to land the attested transaction */
+const ATTESTATION_TTL_SECONDS = 5 * 60;
+
+export interface DeployAttestationResponse {
+  deployer: string;
+  action: DeployAttestationDto['action'];
+  programAddress: string;
+  programHash: string;
+  programSize: number;
+  expiry: number;
+  /** u64, as a string because it may exceed Number.MAX_SAFE_INTEGER */
+  nonce: string;
+  attestationKey: string;
+  /** Base64 ed25519 signature over the domain-prefixed attestation */
+  signature: string;
+}
+
+/**
+ * AttestationService - signs the DeployAttestation the program-deployer
+ * program requires before register_deployment and upgrade_hosted.
+ *
+ * The signing key is ATTESTATION_KEYPAIR, a JSON byte array like KEYPAIR;
+ * its public key must be the attestation key in the on-chain config.
+ *
+ * Only wallets linked to the caller's account are attested, within per-user
+ * program size, active program and daily attestation limits.
+ */
+@Injectable()
+export class AttestationService {
+  private signer?: Awaited<ReturnType<typeof createKeyPairSignerFromBytes>>;
+  private initPromise: Promise<void>;
+
+  // Limit configuration (read from env or use defaults)
+  private readonly MAX_PROGRAM_SIZE: number;
+  private readonly MAX_ACTIVE_PROGRAMS: number;
+  private readonly DAILY_ATTESTATION_LIMIT: number;
+
+  constructor(
+    private authMethodsRepository: AuthMethodsRepository,
+    private programsRepository: ProgramsRepository,
+    private storageService: StorageService,
+  ) {
+    this.MAX_PROGRAM_SIZE = parseInt(
+      process.env.DEPLOY_MAX_PROGRAM_SIZE || '1048576',
+      10,
+    );
+    this.MAX_ACTIVE_PROGRAMS = parseInt(
+      process.env.DEPLOY_MAX_ACTIVE_PROGRAMS || '5',
+      10,
+    );
+    this.DAILY_ATTESTATION_LIMIT = parseInt(
+      process.env.DEPLOY_DAILY_ATTESTATION_LIMIT || '20',
+      10,
+    );
+
+    const keypairSecret = process.env.ATTESTATION_KEYPAIR;
+    this.initPromise = keypairSecret
+      ? this.initialize(keypairSecret)
+      : Promise.resolve();
+  }
+
+  private async initialize(keypairSecret: string) {
+    this.signer = await createKeyPairSignerFromBytes(
+      new Uint8Array(JSON.parse(keypairSecret)),
+    );
+  }
+
+  async attest(
+    userId: string,
+    dto: DeployAttestationDto,
+  ): Promise<DeployAttestationResponse> {
+    await this.initPromise;
+    if (!this.signer) {
+      throw new ServiceUnavailableException(
+        'Hosted deployments are not configured on this server',
+      );
+    }
+    if (!isAddress(dto.deployer)) {
+      throw new BadRequestException('deployer must be a base58 address');
+    }
+    if (!isAddress(dto.programAddress)) {
+      throw new BadRequestException('programAddress must be a base58 address');
+    }
+
+    await this.checkDeployer(userId, dto.deployer);
+    await this.checkLimits(userId, dto);
+
+    const expiry = Math.floor(Date.now() / 1000) + ATTESTATION_TTL_SECONDS;
+    // Random rather than sequential: the program rejects reused nonces
+    const nonce = randomBytes(8).readBigUInt64LE();
+
+    // Borsh layout of DeployAttestation: user, action, program, program_hash,
+    // program_size, expiry, nonce
+    const addressEncoder = getAddressEncoder();
+    const attestation = Buffer.alloc(32 + 1 + 32 + 32 + 4 + 8 + 8);
+    Buffer.from(addressEncoder.encode(address(dto.deployer))).copy(
+      attestation,
+      0,
+    );
+    attestation.writeUInt8(ATTESTATION_ACTIONS[dto.action], 32);
+    Buffer.from(addressEncoder.encode(address(dto.programAddress))).copy(
+      attestation,
+      33,
+    );
+    Buffer.from(dto.programHash, 'hex').copy(attestation, 65);
+    attestation.writeUInt32LE(dto.programSize, 97);
+    attestation.writeBigInt64LE(BigInt(expiry), 101);
+    attestation.writeBigUInt64LE(nonce, 109);
+
+    const signature = await signBytes(
+      this.signer.keyPair.privateKey,
+      Buffer.concat([ATTESTATION_DOMAIN, attestation]),
+    );
+
+    return {
+      deployer: dto.deployer,
+      action: dto.action,
+      programAddress: dto.programAddress,
+      programHash: dto.programHash,
+      programSize: dto.programSize,
+      expiry,
+      nonce: nonce.toString(),
+      attestationKey: this.signer.address,
+      signature: Buffer.from(signature).toString('base64'),
+    };
+  }
+
+  /**
+   * Only attest wallets the user has linked, so an API token can't approve
+   * deployments signed by someone else's wallet
+   */
+  private async checkDeployer(userId: string, deployer: string) {
+    const authMethods = await this.authMethodsRepository.listByUser(userId);
+    const linked = authMethods.some(
+      (method) =>
+        method.provider === 'wallet' &&
+        method.verified &&
+        method.providerId === deployer,
+    );
+    if (!linked) {
+      throw new ForbiddenException(
+        `${deployer} is not a wallet linked to your account`,
+      );
+    }
+  }
+
+  /**
+   * Enforce the per-user program size, active program and daily attestation
+   * limits; the daily count is only spent once the other checks pass
+   */
+  private async checkLimits(userId: string, dto: DeployAttestationDto) {
+    if (dto.programSize > this.MAX_PROGRAM_SIZE) {
+      throw new BadRequestException(
+        `Programs are limited to ${this.MAX_PROGRAM_SIZE} bytes`,
+      );
+    }
+
+    if (dto.action === 'register') {
+      const active = await this.programsRepository.countActiveByUser(userId);
+      if (active >= this.MAX_ACTIVE_PROGRAMS) {
+        throw new HttpException(
+          `Hosting limit of ${this.MAX_ACTIVE_PROGRAMS} active programs reached`,
+          HttpStatus.TOO_MANY_REQUESTS,
+        );
+      }
+    }
+
+    const today = new Date().toISOString().split('T')[0]; // YYYY-MM-DD
+    const dailyKey = `attestation_user:${userId}:${today}`;
+    const used = await this.storageService.incr(dailyKey);
+    if (used === 1) {
+      await this.storageService.expire(dailyKey, 24 * 60 * 60);
+    }
+    if (used > this.DAILY_ATTESTATION_LIMIT) {
+      throw new HttpException(
+        `Daily limit of ${this.DAILY_ATTESTATION_LIMIT} deploy attestations reached`,
+        HttpStatus.TOO_MANY_REQUESTS,
+      );
+    }
+  }
+}
@@ -0,0 +1,100 @@
+use crate::config::Config;
+use crate::error::{CliError, Result};
+use crate::keys::{resolve_keypair_path, KeyStore};
+use solana_pubkey::{ParsePubkeyError, Pubkey};
+use std::path::{Path, PathBuf};
+
+/// Default Solana CLI keypair location, relative to the home directory
+const DEFAULT_KEYPAIR_PATH: &str = ".config/solana/id.json";
+
+/// Parse a base58 address, checking that it decodes to 32 bytes
+pub fn parse_address(address: &str) -> Result<Pubkey> {
+    address.parse().map_err(|e| match e {
+        ParsePubkeyError::WrongSize => CliError::InvalidInput(format!(
+            "Invalid address {}: must decode to 32 bytes",
+            address
+        )),
+        ParsePubkeyError::Invalid => {
+            CliError::InvalidInput(format!("Invalid address {}: not valid base58", address))
+        }
+    })
+}
+
+/// Resolve an address argument that may be a base58 pubkey, a keypair file,
+/// or the name of a key created with `sdt keys`
+///
+/// When omitted, the configured keypair (or the Solana CLI default keypair)
+/// is used.
+pub fn resolve_address(input: Option<&str>, config: &Config) -> Result<Pubkey> {
+    let Some(input) = input else {
+        let path = default_keypair_path(config)?;
+        if !path.is_file() {
+            return Err(CliError::InvalidInput(format!(
+                "No address given and no keypair found at {}. Pass an address or set wallet.keypair",
+                path.display()
+            )));
+        }
+        return read_keypair_pubkey(&path);
+    };
+
+    let path = expand_tilde(input);
+    if path.is_file() {
+        return read_keypair_pubkey(&path);
+    }
+
+    let store = KeyStore::open()?;
+    if store.contains(input) {
+        return read_keypair_pubkey(&store.path(input));
+    }
+
+    parse_address(input)
+}
+
+/// Path of the keypair used when no address is given
+pub fn default_keypair_path(config: &Config) -> Result<PathBuf> {
+    if let Some(ref reference) = config.wallet.keypair {
+        return resolve_keypair_path(reference);
+    }
+
+    dirs::home_dir()
+        .map(|home| home.join(DEFAULT_KEYPAIR_PATH))
+        .ok_or_else(|| CliError::ConfigError("Could not determine home directory".to_string()))
+}
+
+/// Read the public key from a Solana keypair JSON file (64-byte array)
+pub fn read_keypair_pubkey(path: &Path) -> Result<Pubkey> {
+    let bytes = read_keypair_bytes(path)?;
+    Ok(Pubkey::new_from_array(
+        bytes[32..].try_into().expect("keypair is 64 bytes"),
+    ))
+}
+
+/// Read the raw 64 bytes (secret key followed by public key) of a keypair file
+pub fn read_keypair_bytes(path: &Path) -> Result<[u8; 64]> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        CliError::InvalidInput(format!("Cannot read keypair {}: {}", path.display(), e))
+    })?;
+
+    let bytes: Vec<u8> = serde_json::from_str(&contents).map_err(|_| {
+        CliError::InvalidInput(format!(
+            "{} is not a Solana keypair file (expected a JSON array of 64 bytes)",
+            path.display()
+        ))
+    })?;
+
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        CliError::InvalidInput(format!(
+            "{} contains {} bytes, expected 64",
+            path.display(),
+            bytes.len()
+        ))
+    })
+}
+
+/// Expand a leading `~/` to the user's home directory
+pub fn expand_tilde(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
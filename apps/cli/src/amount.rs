@@ -0,0 +1,311 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Number of lamports in one SOL
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Number of decimal places in a SOL amount
+pub const SOL_DECIMALS: usize = 9;
+
+const LAMPORTS_SUFFIXES: [&str; 2] = ["lamports", "lamport"];
+const SOL_SUFFIX: &str = "sol";
+
+/// An exact amount of SOL, stored as lamports
+///
+/// Parses decimal SOL amounts ("1.5", "1.5 SOL") with up to 9 decimal places
+/// or integer lamport amounts ("1500000000lamports"), and always displays as
+/// SOL without floating point rounding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lamports(pub u64);
+
+impl Lamports {
+    pub const ZERO: Lamports = Lamports(0);
+
+    /// Whole and fractional SOL, e.g. `1.5`
+    pub fn to_sol_string(&self) -> String {
+        let whole = self.0 / LAMPORTS_PER_SOL;
+        let fraction = self.0 % LAMPORTS_PER_SOL;
+
+        if fraction == 0 {
+            return whole.to_string();
+        }
+
+        let fraction = format!("{:0width$}", fraction, width = SOL_DECIMALS);
+        format!("{}.{}", whole, fraction.trim_end_matches('0'))
+    }
+
+    pub fn checked_add(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_add(other.0).map(Lamports)
+    }
+
+    pub fn saturating_sub(self, other: Lamports) -> Lamports {
+        Lamports(self.0.saturating_sub(other.0))
+    }
+
+    /// Parse a decimal SOL string, rejecting more than 9 decimal places
+    pub fn from_sol_str(s: &str) -> Result<Self, String> {
+        parse_sol(s, false)
+    }
+}
+
+/// Parse a decimal SOL string into lamports, optionally rounding excess precision
+fn parse_sol(s: &str, round: bool) -> Result<Lamports, String> {
+    let invalid = || format!("Invalid SOL amount: {}", s);
+
+    let (whole, fraction) = match s.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (s, ""),
+    };
+
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let mut fraction = fraction.to_string();
+    let mut round_up = false;
+    if fraction.len() > SOL_DECIMALS {
+        if !round {
+            return Err(format!(
+                "SOL amounts support at most {} decimal places: {}",
+                SOL_DECIMALS, s
+            ));
+        }
+        round_up = fraction.as_bytes()[SOL_DECIMALS] >= b'5';
+        fraction.truncate(SOL_DECIMALS);
+    }
+
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| invalid())?
+    };
+    let fraction: u64 = format!("{:0<width$}", fraction, width = SOL_DECIMALS)
+        .parse()
+        .map_err(|_| invalid())?;
+
+    whole
+        .checked_mul(LAMPORTS_PER_SOL)
+        .and_then(|lamports| lamports.checked_add(fraction))
+        .and_then(|lamports| lamports.checked_add(round_up as u64))
+        .map(Lamports)
+        .ok_or_else(|| format!("SOL amount out of range: {}", s))
+}
+
+impl FromStr for Lamports {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().replace('_', "").to_lowercase();
+
+        for suffix in LAMPORTS_SUFFIXES {
+            if let Some(lamports) = normalized.strip_suffix(suffix) {
+                return lamports
+                    .trim()
+                    .parse()
+                    .map(Lamports)
+                    .map_err(|_| format!("Invalid lamport amount: {}", s));
+            }
+        }
+
+        let sol = normalized
+            .strip_suffix(SOL_SUFFIX)
+            .unwrap_or(&normalized)
+            .trim();
+
+        parse_sol(sol, false)
+    }
+}
+
+impl fmt::Display for Lamports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} SOL", self.to_sol_string())
+    }
+}
+
+/// Serde adapter for APIs that exchange amounts as JSON numbers in SOL
+///
+/// Use with `#[serde(with = "crate::amount::sol")]`. Incoming values are
+/// rounded to the nearest lamport to absorb floating point noise.
+pub mod sol {
+    use super::{parse_sol, Lamports};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(amount: &Lamports, serializer: S) -> Result<S::Ok, S::Error> {
+        let sol: f64 = amount
+            .to_sol_string()
+            .parse()
+            .map_err(serde::ser::Error::custom)?;
+        serializer.serialize_f64(sol)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Lamports, D::Error> {
+        deserialize_amount(deserializer, true)
+    }
+
+    pub(super) fn deserialize_amount<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        round: bool,
+    ) -> Result<Lamports, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum SolValue {
+            Number(f64),
+            String(String),
+        }
+
+        let text = match SolValue::deserialize(deserializer)? {
+            // Shortest round-trip representation, never exponent notation
+            SolValue::Number(n) if n.is_finite() && n >= 0.0 => format!("{}", n),
+            SolValue::Number(n) => {
+                return Err(de::Error::custom(format!("Invalid SOL amount: {}", n)))
+            }
+            SolValue::String(s) => s,
+        };
+
+        parse_sol(text.trim(), round).map_err(de::Error::custom)
+    }
+}
+
+/// Serde adapter for SOL amounts people write, such as batch files
+///
+/// Use with `#[serde(with = "crate::amount::sol_exact")]`. Like [`sol`], but
+/// rejects more than 9 decimal places instead of rounding, as the command
+/// line does.
+pub mod sol_exact {
+    use super::Lamports;
+    use serde::Deserializer;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Lamports, D::Error> {
+        super::sol::deserialize_amount(deserializer, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Lamports, String> {
+        s.parse()
+    }
+
+    fn from_json(json: &str) -> Result<Lamports, serde_json::Error> {
+        #[derive(serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::amount::sol")]
+            amount: Lamports,
+        }
+
+        serde_json::from_str::<Wrapper>(&format!("{{\"amount\": {}}}", json))
+            .map(|wrapper| wrapper.amount)
+    }
+
+    fn from_exact_json(json: &str) -> Result<Lamports, serde_json::Error> {
+        #[derive(serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::amount::sol_exact")]
+            amount: Lamports,
+        }
+
+        serde_json::from_str::<Wrapper>(&format!("{{\"amount\": {}}}", json))
+            .map(|wrapper| wrapper.amount)
+    }
+
+    #[test]
+    fn parses_decimal_sol() {
+        assert_eq!(parse("1.5"), Ok(Lamports(1_500_000_000)));
+        assert_eq!(parse("2 SOL"), Ok(Lamports(2 * LAMPORTS_PER_SOL)));
+        assert_eq!(parse(".25"), Ok(Lamports(250_000_000)));
+        assert_eq!(parse("0.000000001"), Ok(Lamports(1)));
+        assert_eq!(parse("1_000"), Ok(Lamports(1_000 * LAMPORTS_PER_SOL)));
+    }
+
+    #[test]
+    fn parses_lamports() {
+        assert_eq!(parse("1500lamports"), Ok(Lamports(1_500)));
+        assert_eq!(parse("1 lamport"), Ok(Lamports(1)));
+        assert!(parse("1.5 lamports").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        for input in ["", ".", "-1", "1e-9", "abc", "1.2.3", "+1"] {
+            assert!(parse(input).is_err(), "{:?} should be rejected", input);
+        }
+    }
+
+    #[test]
+    fn rejects_more_than_nine_decimal_places() {
+        let err = parse("0.0000000001").unwrap_err();
+        assert!(err.contains("at most 9 decimal places"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        // u64::MAX lamports is about 18.45 billion SOL
+        assert!(parse("18446744073.709551615").is_ok());
+        assert!(parse("18446744073.709551616").is_err());
+        assert!(parse("99999999999999999999").is_err());
+        assert!(parse("18446744073709551616 lamports").is_err());
+    }
+
+    #[test]
+    fn displays_exact_sol() {
+        assert_eq!(Lamports(1).to_string(), "0.000000001 SOL");
+        assert_eq!(Lamports(1_500_000_000).to_string(), "1.5 SOL");
+        assert_eq!(Lamports(3 * LAMPORTS_PER_SOL).to_string(), "3 SOL");
+        assert_eq!(Lamports::ZERO.to_string(), "0 SOL");
+    }
+
+    #[test]
+    fn serde_adapter_reads_numbers_and_strings() {
+        assert_eq!(from_json("1.5").unwrap(), Lamports(1_500_000_000));
+        assert_eq!(from_json("1e-9").unwrap(), Lamports(1));
+        assert_eq!(from_json("\"0.25\"").unwrap(), Lamports(250_000_000));
+        assert_eq!(from_json("2").unwrap(), Lamports(2 * LAMPORTS_PER_SOL));
+    }
+
+    #[test]
+    fn api_adapter_rounds_float_noise_but_exact_adapter_rejects_it() {
+        assert_eq!(
+            from_json("0.30000000000000004").unwrap(),
+            Lamports(300_000_000)
+        );
+        assert_eq!(from_json("\"0.0000000015\"").unwrap(), Lamports(2));
+        assert_eq!(from_json("\"0.0000000014\"").unwrap(), Lamports(1));
+
+        for json in ["0.30000000000000004", "\"0.0000000015\"", "1e-10"] {
+            let err = from_exact_json(json).unwrap_err();
+            assert!(
+                err.to_string().contains("at most 9 decimal places"),
+                "{}",
+                err
+            );
+        }
+        assert_eq!(from_exact_json("1.5").unwrap(), Lamports(1_500_000_000));
+        assert_eq!(from_exact_json("\"0.000000001\"").unwrap(), Lamports(1));
+    }
+
+    #[test]
+    fn serde_adapter_rejects_negative_amounts() {
+        assert!(from_json("-1").is_err());
+        assert!(from_json("\"-0.5\"").is_err());
+    }
+
+    #[test]
+    fn serde_adapter_writes_sol_numbers() {
+        #[derive(serde::Serialize)]
+        struct Wrapper {
+            #[serde(with = "crate::amount::sol")]
+            amount: Lamports,
+        }
+
+        let json = serde_json::to_string(&Wrapper {
+            amount: Lamports(1_500_000_001),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"amount":1.500000001}"#);
+    }
+}
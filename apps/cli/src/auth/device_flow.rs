@@ -47,7 +47,7 @@ impl DeviceFlowAuth {
             .post(&url)
             .send()
             .await
-            .map_err(|e| CliError::NetworkError(e))?;
+            .map_err(CliError::NetworkError)?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -71,7 +71,7 @@ impl DeviceFlowAuth {
             .get(&url)
             .send()
             .await
-            .map_err(|e| CliError::NetworkError(e))?;
+            .map_err(CliError::NetworkError)?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -1,4 +1,11 @@
-use clap::{Parser, Subcommand};
+use crate::amount::Lamports;
+use crate::client::api::Commitment;
+use crate::cluster::Cluster;
+use crate::config::OutputFormat;
+use crate::export::ExportArgs;
+use crate::fees::PriorityFeeArgs;
+use chrono::NaiveDate;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 #[derive(Debug, Parser)]
 #[command(name = "sdt")]
@@ -28,9 +35,13 @@ pub struct Cli {
     #[arg(long, global = true, env = "SDT_API_BASE_URL")]
     pub api_url: Option<String>,
 
-    /// Output format
-    #[arg(long, global = true, value_parser = ["json", "table", "text"])]
-    pub output: Option<String>,
+    /// Target cluster (defaults to network.cluster)
+    #[arg(short, long, global = true, env = "SDT_CLUSTER", value_enum)]
+    pub cluster: Option<Cluster>,
+
+    /// Output format (defaults to output.format)
+    #[arg(long, global = true, value_enum)]
+    pub output: Option<OutputFormat>,
 
     /// Disable colored output
     #[arg(long, global = true)]
@@ -88,12 +99,53 @@ EXAMPLES:
         command: ApiKeyCommands,
     },
 
+    /// Local keypair management
+    #[command(long_about = "\
+Create, import and inspect local signing keypairs
+
+Keys are stored as standard Solana CLI keypair files (a JSON array of 64
+bytes) under the sdt config directory, readable only by you. A stored key
+can be used anywhere an address or keypair file is accepted by passing its
+name, and becomes the default signer with --default or
+'sdt config set wallet.keypair <name>'.
+
+EXAMPLES:
+  sdt keys new dev --default
+  sdt keys new phantom --derivation-path
+  sdt keys import ci --file ./ci-keypair.json
+  sdt keys import restored --seed-phrase --derivation-path \"m/44'/501'/1'/0'\"
+  sdt keys list
+  sdt keys export-pubkey dev
+")]
+    Keys {
+        #[command(subcommand)]
+        command: KeysCommands,
+    },
+
     /// Configuration management
     Config {
         #[command(subcommand)]
         command: ConfigCommands,
     },
 
+    /// RPC response cache management
+    #[command(long_about = "\
+Inspect and clear the on-disk RPC response cache
+
+When enabled with 'sdt config set cache.enabled true', read-only RPC calls
+are cached locally. Finalized transactions and blocks are cached forever;
+mutable reads such as getAccountInfo and getProgramAccounts expire after
+cache.ttl_seconds. Pass --no-cache to 'sdt rpc call' to bypass the cache.
+
+EXAMPLES:
+  sdt cache stats
+  sdt cache clear
+")]
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
     /// RPC operations (send JSON-RPC requests through authenticated proxy)
     #[command(long_about = "\
 Send Solana JSON-RPC requests through authenticated load-balanced proxy
@@ -110,6 +162,8 @@ EXAMPLES:
   Get block height:       sdt rpc call getBlockHeight
   Show provider info:     sdt rpc info
 
+Read-only calls are served from the local cache when 'cache.enabled' is set.
+
 Note: Params must be valid JSON array format when provided.
 ")]
     Rpc {
@@ -121,15 +175,14 @@ Note: Params must be valid JSON array format when provided.
     #[command(long_about = "\
 Deploy Solana programs with rent-free temporary hosting
 
-Programs are deployed and hosted for free with a 7-day expiration period.
-You must claim authority within 7 days or the program will be reclaimed.
+Programs are deployed to devnet and hosted for free with a 7-day expiration
+period. You must claim authority within 7 days or the program will be reclaimed.
 
 Projects automatically organize your program deployments. If a project
 doesn't exist, it will be created automatically.
 
 EXAMPLES:
   Deploy to devnet:     sdt deploy program ./target/deploy/my_program.so
-  Deploy to testnet:    sdt deploy program ./target/deploy/my_program.so --cluster testnet
   Custom project name:  sdt deploy program ./program.so --project my-project
   With description:     sdt deploy program ./program.so --description 'My awesome program'
 ")]
@@ -137,6 +190,294 @@ EXAMPLES:
         #[command(subcommand)]
         command: DeployCommands,
     },
+
+    /// Manage hosted programs
+    #[command(long_about = "\
+List and manage programs deployed with 'sdt deploy program'
+
+EXAMPLES:
+  sdt programs list
+  sdt programs list --all --export ndjson
+  sdt programs extend <program-id> --days 7
+")]
+    Programs {
+        #[command(subcommand)]
+        command: ProgramsCommands,
+    },
+
+    /// Wallet inspection
+    #[command(long_about = "\
+Inspect wallet balances
+
+EXAMPLES:
+  sdt wallet balances <address>
+  sdt wallet balances ~/.config/solana/id.json --sort value
+  sdt --output json wallet balances <address>
+")]
+    Wallet {
+        #[command(subcommand)]
+        command: WalletCommands,
+    },
+
+    /// Stream program logs in real time
+    #[command(long_about = "\
+Stream transaction logs for a program over a websocket subscription
+
+Opens a logsSubscribe subscription on the websocket URL given with --ws-url
+or configured with 'sdt config set rpc.ws_url <url>' and prints
+each 'Program log:' line along with the transaction signature and slot.
+The connection is re-established automatically with exponential backoff.
+
+With --mentions, only the program's transactions that also mention one of
+the given addresses are shown. Transactions whose logs don't name the
+address are looked up through the API to check their account keys.
+
+The API has no websocket endpoint, so a websocket URL is required.
+
+EXAMPLES:
+  Set an endpoint once:   sdt config set rpc.ws_url wss://api.devnet.solana.com
+  Stream a program:       sdt logs <program-id>
+  Only one account:       sdt logs <program-id> --mentions <address>
+  Other endpoint:         sdt logs <program-id> --ws-url wss://api.testnet.solana.com
+")]
+    Logs(LogsArgs),
+
+    /// Estimate priority fees from recent prioritization fees
+    #[command(long_about = "\
+Estimate priority fees using getRecentPrioritizationFees through the proxy
+
+Reports percentiles of the compute unit prices (in micro-lamports) paid by
+recent transactions that wrote to the given accounts. Without --account,
+fees are sampled across all recent transactions.
+
+Commands that send transactions accept --priority-fee <PERCENTILE> to attach
+ComputeBudget instructions priced at that percentile automatically.
+
+EXAMPLES:
+  sdt fees
+  sdt fees --account <writable-address> --account <another-address>
+  sdt fees --percentile 90
+")]
+    Fees {
+        /// Writable account to sample fees for (repeatable)
+        #[arg(long = "account")]
+        accounts: Vec<String>,
+
+        /// Percentile used for the suggested compute unit price
+        #[arg(long, default_value = "75", value_parser = clap::value_parser!(u8).range(0..=100))]
+        percentile: u8,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct LogsArgs {
+    /// Program ID to stream logs for
+    pub program_id: String,
+
+    /// Only show transactions that also mention this address (repeatable)
+    #[arg(long)]
+    pub mentions: Vec<String>,
+
+    /// Websocket URL (overrides rpc.ws_url)
+    #[arg(long)]
+    pub ws_url: Option<String>,
+
+    /// Commitment level for notifications
+    #[arg(long, default_value = "confirmed", value_parser = ["processed", "confirmed", "finalized"])]
+    pub commitment: String,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProgramsCommands {
+    /// List your hosted programs
+    #[command(long_about = "\
+List programs you have deployed, newest first
+
+Use --all to follow every page (--limit becomes the page size) and
+--export to print CSV or NDJSON with full addresses instead of a table.
+
+EXAMPLES:
+  sdt programs list
+  sdt programs list --all --export csv --out programs.csv
+")]
+    List {
+        /// Maximum number of programs to show (page size with --all)
+        #[arg(long, default_value = "50")]
+        limit: u32,
+
+        /// Offset for pagination
+        #[arg(long, default_value = "0")]
+        offset: u32,
+
+        /// Fetch every page
+        #[arg(long)]
+        all: bool,
+
+        #[command(flatten)]
+        export: ExportArgs,
+    },
+
+    /// Pay to keep a hosted program deployed for longer
+    #[command(long_about = "\
+Push back a hosted program's expiry by a number of days
+
+Sends an extend_hosting transaction to the program deployer, signed by your
+default signer (wallet.keypair) or --keypair. The fee is paid in SOL, or in
+the deployer's configured stable token with --pay-in-token. Anyone may pay
+for an extension, not only the wallet that deployed the program.
+
+EXAMPLES:
+  sdt programs extend <program-id> --days 7
+  sdt programs extend <program-id> --days 3 --pay-in-token --keypair ci
+  sdt programs extend <program-id> --days 7 --priority-fee 75
+")]
+    Extend {
+        /// Address of the hosted program
+        program: String,
+
+        /// Number of days to extend hosting by
+        #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+        days: u16,
+
+        /// Pay the fee in the deployer's stable token instead of SOL
+        #[arg(long)]
+        pay_in_token: bool,
+
+        /// Keypair file or 'sdt keys' name to pay with (defaults to wallet.keypair)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Seconds to wait for confirmation
+        #[arg(long, default_value = "60")]
+        timeout: u64,
+
+        #[command(flatten)]
+        fee: PriorityFeeArgs,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum KeysCommands {
+    /// Generate a new keypair from a fresh BIP39 seed phrase
+    New {
+        /// Name to store the key under
+        name: String,
+
+        /// Number of words in the seed phrase
+        #[arg(long, default_value = "12", value_parser = ["12", "15", "18", "21", "24"])]
+        word_count: String,
+
+        #[command(flatten)]
+        derivation: DerivationArgs,
+
+        /// Make this key the default signer (wallet.keypair)
+        #[arg(long)]
+        default: bool,
+
+        /// Overwrite an existing key with the same name
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Import a keypair file or recover a key from a seed phrase
+    #[command(group(clap::ArgGroup::new("source").required(true).args(["file", "seed_phrase"])))]
+    Import {
+        /// Name to store the key under
+        name: String,
+
+        /// Solana CLI keypair file to import
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+
+        /// Prompt for a BIP39 seed phrase to recover the key from
+        #[arg(long)]
+        seed_phrase: bool,
+
+        #[command(flatten)]
+        derivation: DerivationArgs,
+
+        /// Make this key the default signer (wallet.keypair)
+        #[arg(long)]
+        default: bool,
+
+        /// Overwrite an existing key with the same name
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// List stored keys
+    List,
+
+    /// Show a stored key (defaults to the default signer)
+    Show {
+        /// Key name
+        name: Option<String>,
+    },
+
+    /// Print only the public key, for use in scripts
+    ExportPubkey {
+        /// Key name (defaults to the default signer)
+        name: Option<String>,
+    },
+}
+
+/// How a keypair is derived from a seed phrase
+#[derive(Debug, Clone, Args)]
+pub struct DerivationArgs {
+    /// Derive with a BIP44 path, e.g. "m/44'/501'/0'/0'" or "<account>/<change>"
+    /// (without a value: m/44'/501'/0'/0', as used by most wallets)
+    #[arg(long, num_args = 0..=1, default_missing_value = crate::keys::DEFAULT_DERIVATION_PATH)]
+    pub derivation_path: Option<String>,
+
+    /// Prompt for a BIP39 passphrase
+    #[arg(long)]
+    pub passphrase: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WalletCommands {
+    /// Show SOL and token balances
+    #[command(long_about = "\
+Show the SOL balance and SPL Token / Token-2022 holdings of a wallet
+
+SPL Token balances come from the API's wallet service; Token-2022 balances
+are read through the RPC proxy. Amounts are adjusted for each mint's
+decimals. Symbols and USD values are shown when the API provides them.
+
+Use the global --output json flag for machine-readable output.
+
+EXAMPLES:
+  sdt wallet balances <address>
+  sdt wallet balances --limit 10 --sort mint
+  sdt --output json wallet balances ~/.config/solana/id.json
+")]
+    Balances {
+        /// Wallet address or keypair file (defaults to the configured keypair)
+        address: Option<String>,
+
+        /// Maximum number of token holdings to show
+        #[arg(long)]
+        limit: Option<u32>,
+
+        /// Order of token holdings
+        #[arg(long, value_enum, default_value = "amount")]
+        sort: BalanceSort,
+    },
+}
+
+/// Ordering of token holdings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BalanceSort {
+    /// Largest amount first
+    Amount,
+    /// Alphabetically by mint address
+    Mint,
+    /// Largest USD value first (holdings without a value last)
+    Value,
 }
 
 #[derive(Debug, Subcommand)]
@@ -194,15 +535,123 @@ Rate limits apply:
 
 Check 'sdt airdrop usage' to see your current limits and usage.
 
-EXAMPLE:
+The CLI checks the amount against your remaining quota before sending the
+request and offers to request the largest allowed amount instead. Pass
+--force to skip the check and let the API decide. With 'cache.enabled' set,
+the quota is kept in the response cache for up to a minute.
+
+Amounts are exact: SOL with up to 9 decimal places, or an integer number
+of lamports with a 'lamports' suffix.
+
+The recipient may be a base58 address or a path to a Solana keypair file.
+When omitted, the keypair configured with 'sdt config set wallet.keypair'
+(or ~/.config/solana/id.json) is used. Addresses are validated locally
+before contacting the API.
+
+With --wait, the CLI polls the transaction status through the RPC proxy until
+it reaches the requested commitment, then shows the recipient's balance. The
+command exits non-zero if the transaction failed or did not confirm in time.
+
+EXAMPLES:
   sdt airdrop request 1.0 9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde
+  sdt airdrop request 500000000lamports 9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde
+  sdt airdrop request 1.0 ~/.config/solana/id.json
+  sdt airdrop request 1.0 --wait --commitment finalized
+  sdt airdrop request 5 --force
 ")]
     Request {
-        /// Amount of SOL to airdrop
-        amount: f64,
+        /// Amount of SOL to airdrop (e.g. 1.5, or 500000000lamports)
+        amount: Lamports,
 
-        /// Recipient wallet address
-        recipient: String,
+        /// Recipient address or keypair file (defaults to the configured keypair)
+        recipient: Option<String>,
+
+        /// Wait for the transaction to confirm and show the recipient's balance
+        #[arg(long)]
+        wait: bool,
+
+        /// Commitment level to wait for
+        #[arg(long, value_enum, default_value = "confirmed", requires = "wait")]
+        commitment: Commitment,
+
+        /// Seconds to wait for confirmation
+        #[arg(long, default_value = "60", requires = "wait")]
+        timeout: u64,
+
+        /// Skip the local rate-limit check
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Top up a wallet until it holds at least a minimum balance
+    #[command(long_about = "\
+Top up a wallet until it holds at least a minimum balance
+
+Reads the current balance through the RPC proxy and requests only the
+shortfall, split into as many airdrops as the per-request maximum requires.
+Each airdrop is confirmed before the next is sent. Does nothing when the
+wallet is already funded, so it is safe to run at the start of every job.
+
+EXAMPLES:
+  sdt airdrop ensure <address> --min 5
+  sdt airdrop ensure ~/.config/solana/id.json --min 2.5 --commitment finalized
+")]
+    Ensure {
+        /// Wallet address or keypair file (defaults to the configured keypair)
+        recipient: Option<String>,
+
+        /// Minimum balance the wallet should hold (e.g. 5, or 5000000000lamports)
+        #[arg(long)]
+        min: Lamports,
+
+        /// Commitment level for balance reads and confirmations
+        #[arg(long, value_enum, default_value = "confirmed")]
+        commitment: Commitment,
+
+        /// Seconds to wait for each airdrop to confirm
+        #[arg(long, default_value = "60")]
+        timeout: u64,
+    },
+
+    /// Fund many wallets from a CSV or JSON file
+    #[command(long_about = "\
+Request airdrops for every row of a CSV or JSON file
+
+CSV files contain 'address,amount' rows (a header row is optional). JSON
+files contain an array of {\"address\": ..., \"amount\": ...} objects.
+Amounts use the same exact format as 'sdt airdrop request'.
+
+Before sending anything, all rows are validated locally and the total is
+checked against your remaining daily limits from 'sdt airdrop usage'.
+Requests run with bounded concurrency and back off when rate limited.
+
+A CSV report with the status and signature of each row is written as the
+batch progresses. Re-run with --resume to skip rows already funded.
+
+EXAMPLES:
+  sdt airdrop batch wallets.csv
+  sdt airdrop batch wallets.json --concurrency 8 --report funded.csv
+  sdt airdrop batch wallets.csv --resume
+")]
+    Batch {
+        /// CSV or JSON file of address,amount rows
+        file: std::path::PathBuf,
+
+        /// Report file (defaults to <file>.report.csv)
+        #[arg(long)]
+        report: Option<std::path::PathBuf>,
+
+        /// Skip rows already funded according to the report
+        #[arg(long)]
+        resume: bool,
+
+        /// Maximum number of concurrent requests
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+
+        /// Retries per row when rate limited
+        #[arg(long, default_value = "5")]
+        max_retries: u32,
     },
 
     /// View airdrop history
@@ -212,18 +661,35 @@ Display paginated list of past airdrops
 Shows transaction signatures with Solscan explorer links, amounts,
 recipients, and timestamps.
 
+Use --all to follow every page of history (--limit becomes the page size).
+Filters apply to the fetched records; --since also stops paging once older
+airdrops are reached. --export prints CSV or NDJSON with full signatures
+and recipients instead of a table.
+
 EXAMPLES:
   sdt airdrop history
   sdt airdrop history --limit 100 --offset 50
+  sdt airdrop history --all --status failed
+  sdt airdrop history --all --since 2025-01-01 --export csv --out airdrops.csv
 ")]
     History {
-        /// Maximum number of records to show
+        /// Maximum number of records to show (page size with --all)
         #[arg(long, default_value = "50")]
         limit: u32,
 
         /// Offset for pagination
         #[arg(long, default_value = "0")]
         offset: u32,
+
+        /// Fetch every page of history
+        #[arg(long)]
+        all: bool,
+
+        #[command(flatten)]
+        filter: HistoryFilter,
+
+        #[command(flatten)]
+        export: ExportArgs,
     },
 
     /// Show airdrop usage statistics
@@ -233,12 +699,44 @@ Display your airdrop usage statistics and limits
 Shows:
   • Today's usage (requests and volume)
   • Total usage across all time
-  • Your daily limits
+  • Your daily limits, remaining quota and time until the UTC reset
 
-EXAMPLE:
+With --history, also shows a per-day table and sparkline of the last
+--days days.
+
+EXAMPLES:
   sdt airdrop usage
+  sdt airdrop usage --history --days 30
 ")]
-    Usage,
+    Usage {
+        /// Show per-day usage history
+        #[arg(long)]
+        history: bool,
+
+        /// Number of days of history to show
+        #[arg(long, default_value = "30", requires = "history", value_parser = clap::value_parser!(u32).range(1..=365))]
+        days: u32,
+    },
+}
+
+/// Client-side filters for airdrop history
+#[derive(Debug, Clone, Args)]
+pub struct HistoryFilter {
+    /// Only airdrops with this status (e.g. confirmed, failed)
+    #[arg(long)]
+    pub status: Option<String>,
+
+    /// Only airdrops sent to this address
+    #[arg(long)]
+    pub recipient: Option<String>,
+
+    /// Only airdrops on or after this UTC date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<NaiveDate>,
+
+    /// Only airdrops on or before this UTC date (YYYY-MM-DD)
+    #[arg(long)]
+    pub until: Option<NaiveDate>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -250,7 +748,10 @@ pub enum ApiKeyCommands {
     },
 
     /// List all API keys
-    List,
+    List {
+        #[command(flatten)]
+        export: ExportArgs,
+    },
 
     /// Revoke an API key
     Revoke {
@@ -289,6 +790,15 @@ pub enum ConfigCommands {
     Reset,
 }
 
+#[derive(Debug, Subcommand)]
+pub enum CacheCommands {
+    /// Show cache size and hit rate
+    Stats,
+
+    /// Remove all cached responses
+    Clear,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum RpcCommands {
     /// Send a JSON-RPC request to Solana
@@ -311,6 +821,7 @@ EXAMPLES:
   sdt rpc call getSlot
   sdt rpc call getBalance --params '[\"9aE476sH92Vz7DMPyq5WLPkrKWivxeuTKEFKd2sZZcde\"]'
   sdt rpc call getAccountInfo --params '[\"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA\"]'
+  sdt rpc call getTransaction --params '[\"<signature>\"]' --no-cache
 
 Full RPC method reference: https://solana.com/docs/rpc
 ")]
@@ -321,6 +832,10 @@ Full RPC method reference: https://solana.com/docs/rpc
         /// JSON-encoded parameters array (e.g., '["address"]')
         #[arg(long)]
         params: Option<String>,
+
+        /// Bypass the RPC response cache
+        #[arg(long)]
+        no_cache: bool,
     },
 
     /// Show RPC provider information
@@ -334,6 +849,48 @@ EXAMPLE:
   sdt rpc info
 ")]
     Info,
+
+    /// Benchmark RPC latency and health through the proxy
+    #[command(long_about = "\
+Benchmark RPC latency, error rates and slot lag through the proxy
+
+Issues a round-robin mix of RPC calls from N concurrent workers for the
+given duration, then reports p50/p95/p99 latency per method, error counts
+grouped by JSON-RPC error code, and how far behind the highest observed
+slot individual getSlot responses were.
+
+EXAMPLES:
+  sdt rpc bench
+  sdt rpc bench --concurrency 16 --duration 60
+  sdt rpc bench --method getSlot --method getLatestBlockhash
+  sdt rpc bench --export bench-$(date +%F).json
+")]
+    Bench {
+        /// RPC methods to include in the mix (repeatable)
+        #[arg(
+            long = "method",
+            value_delimiter = ',',
+            default_values = ["getSlot", "getLatestBlockhash", "getAccountInfo"],
+            value_parser = ["getSlot", "getLatestBlockhash", "getAccountInfo"]
+        )]
+        methods: Vec<String>,
+
+        /// Number of concurrent workers
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+
+        /// Benchmark duration in seconds
+        #[arg(short, long, default_value = "10")]
+        duration: u64,
+
+        /// Account queried by getAccountInfo
+        #[arg(long, default_value = "11111111111111111111111111111111")]
+        account: String,
+
+        /// Write the report as JSON to this file
+        #[arg(long)]
+        export: Option<std::path::PathBuf>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -342,17 +899,29 @@ pub enum DeployCommands {
     #[command(long_about = "\
 Deploy a Solana program with rent-free temporary hosting
 
-The program will be deployed to the specified cluster and hosted for 7 days.
+The program will be deployed to devnet and hosted for 7 days.
 You must claim authority within this period or it will be reclaimed.
 
 If you don't specify a project name, the current directory name will be used.
 If you don't specify a program name, the filename will be used.
 
+The program is deployed to the address in the <name>-keypair.json next to the
+.so file, which is generated if there is none. The API attests the build,
+the treasury pays for the buffer and program rent, and your wallet only pays
+transaction fees. An interrupted deploy resumes when the command is re-run.
+
+If the program is already hosted and unclaimed, it is upgraded in place
+instead: the new code is written to a buffer your wallet pays for (refunded
+after the upgrade, or closed if it fails) and swapped in by the deployer. The
+program address comes from --program-id, or from the <name>-keypair.json.
+Upgrades cannot grow the program beyond its originally deployed size.
+
 EXAMPLES:
   Basic deploy:         sdt deploy program ./target/deploy/my_program.so
   Custom names:         sdt deploy program ./program.so --project my-dapp --program token-mint
   With description:     sdt deploy program ./program.so --description 'Token minting program'
-  Deploy to testnet:    sdt deploy program ./program.so --cluster testnet
+  Upgrade hosted:       sdt deploy program ./program.so --program-id <program-id>
+  With priority fee:    sdt deploy program ./program.so --priority-fee 75
 ")]
     Program {
         /// Path to the compiled program (.so file)
@@ -366,12 +935,19 @@ EXAMPLES:
         #[arg(short = 'n', long)]
         name: Option<String>,
 
-        /// Target cluster
-        #[arg(short, long, default_value = "devnet", value_parser = ["devnet", "testnet", "mainnet-beta"])]
-        cluster: String,
-
         /// Program description
         #[arg(short, long)]
         description: Option<String>,
+
+        /// Address of an already hosted program to upgrade in place
+        #[arg(long)]
+        program_id: Option<String>,
+
+        /// Keypair file or 'sdt keys' name that deploys and may upgrade the program (defaults to wallet.keypair)
+        #[arg(long)]
+        keypair: Option<String>,
+
+        #[command(flatten)]
+        fee: PriorityFeeArgs,
     },
 }
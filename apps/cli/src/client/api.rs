@@ -1,19 +1,33 @@
+use crate::amount::Lamports;
 use crate::auth::credentials::Credentials;
+use crate::client::cache::RpcCache;
+use crate::cluster::Cluster;
+use crate::deployer::AttestationAction;
 use crate::error::{CliError, Result};
+use base64::Engine;
+use chrono::NaiveDate;
+use clap::ValueEnum;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use solana_hash::Hash;
+use solana_transaction::Transaction;
+use std::fmt;
 use std::time::Duration;
 
 #[derive(Debug, Serialize)]
 pub struct CreateAirdropRequest {
-    pub amount: f64,
+    #[serde(with = "crate::amount::sol")]
+    pub amount: Lamports,
+    #[serde(rename = "address")]
     pub recipient: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct AirdropResponse {
     pub signature: String,
-    pub amount: f64,
+    #[serde(with = "crate::amount::sol")]
+    pub amount: Lamports,
     pub recipient: String,
 }
 
@@ -28,7 +42,8 @@ pub struct AirdropRecord {
     pub id: String,
     pub signature: String,
     pub recipient: String,
-    pub amount: f64,
+    #[serde(with = "crate::amount::sol")]
+    pub amount: Lamports,
     pub status: String,
     #[serde(rename = "createdAt")]
     pub created_at: String,
@@ -54,18 +69,72 @@ pub struct UsageStats {
 pub struct UsageStatsData {
     pub today: DailyUsage,
     pub total: TotalUsage,
+    pub limits: Option<AirdropLimits>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct DailyUsage {
+    #[serde(alias = "count")]
     pub requests: u32,
-    pub amount: f64,
+    #[serde(alias = "volume", with = "crate::amount::sol")]
+    pub amount: Lamports,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct TotalUsage {
+    #[serde(alias = "count")]
     pub requests: u32,
-    pub amount: f64,
+    #[serde(alias = "volume", with = "crate::amount::sol")]
+    pub amount: Lamports,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirdropLimits {
+    #[serde(rename = "dailyVolume", with = "crate::amount::sol")]
+    pub daily_volume: Lamports,
+    #[serde(rename = "dailyRequests")]
+    pub daily_requests: u32,
+    #[serde(rename = "maxPerRequest", with = "crate::amount::sol")]
+    pub max_per_request: Lamports,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsageHistoryResponse {
+    pub period: UsagePeriod,
+    pub aggregated: AggregatedUsage,
+    pub daily: Vec<DailyUsageRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsagePeriod {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AggregatedUsage {
+    #[serde(rename = "totalAirdrops")]
+    pub requests: u32,
+    #[serde(rename = "totalVolume", with = "crate::amount::sol")]
+    pub amount: Lamports,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DailyUsageRecord {
+    /// ISO date or timestamp of the UTC day
+    #[serde(rename = "usageDate")]
+    pub usage_date: String,
+    #[serde(rename = "totalAirdrops")]
+    pub requests: u32,
+    #[serde(rename = "totalVolume", with = "crate::amount::sol")]
+    pub amount: Lamports,
+}
+
+impl DailyUsageRecord {
+    /// The UTC day this record covers
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.usage_date.get(..10)?.parse().ok()
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -79,7 +148,7 @@ pub struct ApiKeysListResponse {
     pub api_keys: Vec<ApiKeyRecord>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApiKeyRecord {
     pub id: String,
     pub name: String,
@@ -89,6 +158,89 @@ pub struct ApiKeyRecord {
     pub last_used_at: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TokenBalancesResponse {
+    pub address: String,
+    pub balances: Vec<TokenBalance>,
+}
+
+/// A token account holding as returned by `GET /:address/balances`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenBalance {
+    pub mint: String,
+    /// Amount adjusted for the mint's decimals
+    pub balance: String,
+    pub decimals: u8,
+    #[serde(rename = "tokenAccount")]
+    pub token_account: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(rename = "usdValue", default, skip_serializing_if = "Option::is_none")]
+    pub usd_value: Option<f64>,
+}
+
+/// A hosted program as returned by `GET /programs`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProgramRecord {
+    pub id: String,
+    #[serde(rename = "programAddress")]
+    pub program_address: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub cluster: String,
+    pub status: String,
+    #[serde(rename = "deployedAt")]
+    pub deployed_at: Option<String>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<String>,
+    #[serde(rename = "claimedAt")]
+    pub claimed_at: Option<String>,
+    #[serde(rename = "claimedByAuthority")]
+    pub claimed_by_authority: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+/// Project and program records to create for a deployed program
+#[derive(Debug, Serialize)]
+pub struct InitiateDeployRequest {
+    #[serde(rename = "projectName")]
+    pub project_name: String,
+    #[serde(rename = "programName")]
+    pub program_name: String,
+    pub description: Option<String>,
+    pub cluster: Cluster,
+    #[serde(rename = "programPath")]
+    pub program_path: Option<String>,
+    #[serde(rename = "programAddress")]
+    pub program_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InitiateDeployResponse {
+    pub project: ProjectResponse,
+    /// ID of the program record, not the on-chain address
+    #[serde(rename = "programId")]
+    pub program_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectResponse {
+    pub id: String,
+}
+
+/// The API's signed approval to register or upgrade to specific program bytes
+#[derive(Debug, Deserialize)]
+pub struct DeployAttestationResponse {
+    pub expiry: i64,
+    /// u64 as a decimal string
+    pub nonce: String,
+    #[serde(rename = "attestationKey")]
+    pub attestation_key: String,
+    /// Base64 ed25519 signature
+    pub signature: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ApiKeyUsageResponse {
     pub usage: ApiKeyUsageStats,
@@ -127,6 +279,123 @@ pub struct JsonRpcError {
     pub message: String,
 }
 
+/// Commitment level of RPC reads, ordered from weakest to strongest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl fmt::Display for Commitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Commitment::Processed => "processed",
+            Commitment::Confirmed => "confirmed",
+            Commitment::Finalized => "finalized",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignatureStatus {
+    pub slot: u64,
+    pub confirmations: Option<u64>,
+    pub err: Option<serde_json::Value>,
+    #[serde(rename = "confirmationStatus")]
+    pub confirmation_status: Option<Commitment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcContextValue<T> {
+    value: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcKeyedAccount<T> {
+    pubkey: String,
+    account: RpcAccount<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcAccount<T> {
+    data: RpcParsedData<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcParsedData<T> {
+    parsed: RpcParsedInfo<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcParsedInfo<T> {
+    info: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParsedTokenAccount {
+    mint: String,
+    #[serde(rename = "tokenAmount")]
+    token_amount: ParsedTokenAmount,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParsedTokenAmount {
+    decimals: u8,
+    #[serde(rename = "uiAmountString")]
+    ui_amount_string: String,
+}
+
+/// Raw account contents
+#[derive(Debug, Clone)]
+pub struct AccountData {
+    pub owner: String,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcEncodedAccount {
+    owner: String,
+    /// `[data, encoding]`
+    data: (String, String),
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcBlockhash {
+    blockhash: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcTransaction {
+    transaction: RpcTransactionBody,
+    meta: Option<RpcTransactionMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcTransactionBody {
+    message: RpcMessage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcMessage {
+    account_keys: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcTransactionMeta {
+    loaded_addresses: Option<RpcLoadedAddresses>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcLoadedAddresses {
+    writable: Vec<String>,
+    readonly: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RpcProviderInfo {
     pub total: u32,
@@ -138,6 +407,8 @@ pub struct ApiClient {
     client: reqwest::Client,
     base_url: String,
     credentials: Option<Credentials>,
+    cache: Option<RpcCache>,
+    cluster: Option<Cluster>,
 }
 
 impl ApiClient {
@@ -149,6 +420,8 @@ impl ApiClient {
             client,
             base_url,
             credentials: None,
+            cache: None,
+            cluster: None,
         })
     }
 
@@ -158,16 +431,37 @@ impl ApiClient {
         self
     }
 
+    /// Serve cacheable RPC reads from an on-disk cache
+    pub fn with_cache(mut self, cache: RpcCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Target a specific cluster for RPC and airdrop requests
+    pub fn with_cluster(mut self, cluster: Cluster) -> Self {
+        self.cluster = Some(cluster);
+        self
+    }
+
+    /// Fail loudly when targeting a cluster the API's proxy and faucet don't serve
+    fn ensure_cluster_served(&self) -> Result<()> {
+        match self.cluster {
+            Some(cluster) if !cluster.served_by_api() => Err(CliError::InvalidInput(format!(
+                "The API only serves devnet; {} is not supported for RPC and airdrop requests",
+                cluster
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     /// Build headers with authorization
     fn build_headers(&self) -> Result<HeaderMap> {
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
         if let Some(ref creds) = self.credentials {
-            let auth_value =
-                HeaderValue::from_str(&format!("Bearer {}", creds.api_token)).map_err(|_| {
-                    CliError::Other("Invalid authorization header value".to_string())
-                })?;
+            let auth_value = HeaderValue::from_str(&format!("Bearer {}", creds.api_token))
+                .map_err(|_| CliError::Other("Invalid authorization header value".to_string()))?;
             headers.insert(AUTHORIZATION, auth_value);
         }
 
@@ -175,7 +469,12 @@ impl ApiClient {
     }
 
     /// Request a SOL airdrop
-    pub async fn request_airdrop(&self, amount: f64, recipient: &str) -> Result<AirdropResponse> {
+    pub async fn request_airdrop(
+        &self,
+        amount: Lamports,
+        recipient: &str,
+    ) -> Result<AirdropResponse> {
+        self.ensure_cluster_served()?;
         let url = format!("{}/airdrop", self.base_url);
         let headers = self.build_headers()?;
 
@@ -232,6 +531,181 @@ impl ApiClient {
         Ok(history)
     }
 
+    /// Get SPL Token balances of a wallet from the wallet service
+    pub async fn get_token_balances(&self, address: &str) -> Result<TokenBalancesResponse> {
+        let url = format!("{}/{}/balances", self.base_url, address);
+        let headers = self.build_headers()?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(CliError::ApiResponseError {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+
+        let balances: TokenBalancesResponse = response.json().await?;
+        Ok(balances)
+    }
+
+    /// List the user's hosted programs, newest first
+    pub async fn list_programs(&self, limit: u32, offset: u32) -> Result<Vec<ProgramRecord>> {
+        let url = format!(
+            "{}/programs?limit={}&offset={}",
+            self.base_url, limit, offset
+        );
+        let headers = self.build_headers()?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(CliError::ApiResponseError {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+
+        let programs: Vec<ProgramRecord> = response.json().await?;
+        Ok(programs)
+    }
+
+    /// Create the project and program records for a deployment
+    pub async fn initiate_deployment(
+        &self,
+        request: &InitiateDeployRequest,
+    ) -> Result<InitiateDeployResponse> {
+        let url = format!("{}/projects/deploy", self.base_url);
+        let headers = self.build_headers()?;
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .json(request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(CliError::ApiResponseError {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+
+        let deploy_response: InitiateDeployResponse = response.json().await?;
+        Ok(deploy_response)
+    }
+
+    /// Ask the API to attest program bytes `deployer` is about to register
+    /// `program` with or upgrade it to
+    pub async fn request_deploy_attestation(
+        &self,
+        deployer: &str,
+        action: AttestationAction,
+        program: &str,
+        program_hash: &[u8; 32],
+        program_size: u32,
+    ) -> Result<DeployAttestationResponse> {
+        let url = format!("{}/projects/deploy/attestation", self.base_url);
+        let headers = self.build_headers()?;
+
+        let program_hash: String = program_hash
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let payload = serde_json::json!({
+            "deployer": deployer,
+            "action": action,
+            "programAddress": program,
+            "programHash": program_hash,
+            "programSize": program_size,
+        });
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .json(&payload)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(CliError::ApiResponseError {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+
+        let attestation: DeployAttestationResponse = response.json().await?;
+        Ok(attestation)
+    }
+
+    /// Record a program's deployment status, e.g. once it is live on-chain
+    pub async fn update_program_status(&self, id: &str, status: &str) -> Result<()> {
+        let url = format!("{}/programs/{}/status", self.base_url, id);
+        let headers = self.build_headers()?;
+
+        let payload = serde_json::json!({
+            "status": status,
+            "deployedAt": chrono::Utc::now().to_rfc3339(),
+        });
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .json(&payload)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(CliError::ApiResponseError {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get per-day airdrop usage between two UTC dates (inclusive)
+    pub async fn get_airdrop_usage_history(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<UsageHistoryResponse> {
+        let url = format!(
+            "{}/airdrop/usage/history?startDate={}&endDate={}",
+            self.base_url, start, end
+        );
+        let headers = self.build_headers()?;
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(CliError::ApiResponseError {
+                status: status.as_u16(),
+                message: text,
+            });
+        }
+
+        let history: UsageHistoryResponse = response.json().await?;
+        Ok(history)
+    }
+
     /// Get airdrop usage statistics
     pub async fn get_airdrop_usage(&self) -> Result<UsageStats> {
         let url = format!("{}/airdrop/usage", self.base_url);
@@ -345,6 +819,21 @@ impl ApiClient {
         method: &str,
         params: Option<serde_json::Value>,
     ) -> Result<JsonRpcResponse> {
+        self.ensure_cluster_served()?;
+
+        if let Some(result) = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.get(method, params.as_ref()))
+        {
+            return Ok(JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: 1,
+                result: Some(result),
+                error: None,
+            });
+        }
+
         let url = format!("{}/rpc", self.base_url);
         let headers = self.build_headers()?;
 
@@ -373,11 +862,181 @@ impl ApiClient {
         }
 
         let rpc_response: JsonRpcResponse = response.json().await?;
+
+        if let (Some(cache), Some(result)) = (&self.cache, &rpc_response.result) {
+            if let Err(e) = cache.put(method, payload.params.as_ref(), result) {
+                tracing::debug!("Failed to cache {} response: {}", method, e);
+            }
+        }
+
         Ok(rpc_response)
     }
 
+    /// Send a JSON-RPC request and deserialize its result, treating RPC errors as failures
+    pub async fn rpc_call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T> {
+        let response = self.send_rpc_request(method, Some(params)).await?;
+
+        if let Some(error) = response.error {
+            return Err(CliError::ApiError(format!(
+                "{} failed: [{}] {}",
+                method, error.code, error.message
+            )));
+        }
+
+        let result = serde_json::from_value(response.result.unwrap_or_default())?;
+        Ok(result)
+    }
+
+    /// Get the balance of an account
+    pub async fn get_balance(&self, address: &str, commitment: Commitment) -> Result<Lamports> {
+        let balance: RpcContextValue<u64> = self
+            .rpc_call(
+                "getBalance",
+                serde_json::json!([address, { "commitment": commitment }]),
+            )
+            .await?;
+        Ok(Lamports(balance.value))
+    }
+
+    /// Get the token accounts of `owner` under a token program through the RPC proxy
+    pub async fn get_token_accounts_by_owner(
+        &self,
+        owner: &str,
+        program_id: &str,
+    ) -> Result<Vec<TokenBalance>> {
+        let accounts: RpcContextValue<Vec<RpcKeyedAccount<ParsedTokenAccount>>> = self
+            .rpc_call(
+                "getTokenAccountsByOwner",
+                serde_json::json!([
+                    owner,
+                    { "programId": program_id },
+                    { "encoding": "jsonParsed" },
+                ]),
+            )
+            .await?;
+
+        Ok(accounts
+            .value
+            .into_iter()
+            .map(|account| {
+                let info = account.account.data.parsed.info;
+                TokenBalance {
+                    mint: info.mint,
+                    balance: info.token_amount.ui_amount_string,
+                    decimals: info.token_amount.decimals,
+                    token_account: account.pubkey,
+                    symbol: None,
+                    usd_value: None,
+                }
+            })
+            .collect())
+    }
+
+    /// Get the status of a transaction, or `None` if it is not known yet
+    pub async fn get_signature_status(&self, signature: &str) -> Result<Option<SignatureStatus>> {
+        let statuses: RpcContextValue<Vec<Option<SignatureStatus>>> = self
+            .rpc_call(
+                "getSignatureStatuses",
+                serde_json::json!([[signature], { "searchTransactionHistory": true }]),
+            )
+            .await?;
+        Ok(statuses.value.into_iter().next().flatten())
+    }
+
+    /// Get every account a confirmed transaction references, including those
+    /// loaded from lookup tables, or `None` if it isn't found
+    pub async fn get_transaction_accounts(&self, signature: &str) -> Result<Option<Vec<String>>> {
+        let transaction: Option<RpcTransaction> = self
+            .rpc_call(
+                "getTransaction",
+                serde_json::json!([signature, {
+                    "encoding": "json",
+                    "commitment": "confirmed",
+                    "maxSupportedTransactionVersion": 0,
+                }]),
+            )
+            .await?;
+
+        Ok(transaction.map(|transaction| {
+            let mut accounts = transaction.transaction.message.account_keys;
+            if let Some(loaded) = transaction.meta.and_then(|meta| meta.loaded_addresses) {
+                accounts.extend(loaded.writable);
+                accounts.extend(loaded.readonly);
+            }
+            accounts
+        }))
+    }
+
+    /// Get an account's owner and data, or `None` if it doesn't exist
+    pub async fn get_account_info(&self, address: &str) -> Result<Option<AccountData>> {
+        let account: RpcContextValue<Option<RpcEncodedAccount>> = self
+            .rpc_call(
+                "getAccountInfo",
+                serde_json::json!([address, { "encoding": "base64", "commitment": "confirmed" }]),
+            )
+            .await?;
+
+        account
+            .value
+            .map(|account| {
+                let data = base64::engine::general_purpose::STANDARD
+                    .decode(&account.data.0)
+                    .map_err(|e| {
+                        CliError::ApiError(format!("Invalid account data for {}: {}", address, e))
+                    })?;
+                Ok(AccountData {
+                    owner: account.owner,
+                    data,
+                })
+            })
+            .transpose()
+    }
+
+    /// Get the lamports an account of `len` bytes needs to be rent exempt
+    pub async fn get_minimum_balance_for_rent_exemption(&self, len: usize) -> Result<u64> {
+        self.rpc_call(
+            "getMinimumBalanceForRentExemption",
+            serde_json::json!([len]),
+        )
+        .await
+    }
+
+    /// Get a recent blockhash to sign transactions with
+    pub async fn get_latest_blockhash(&self) -> Result<Hash> {
+        let blockhash: RpcContextValue<RpcBlockhash> = self
+            .rpc_call(
+                "getLatestBlockhash",
+                serde_json::json!([{ "commitment": "confirmed" }]),
+            )
+            .await?;
+
+        blockhash.value.blockhash.parse().map_err(|_| {
+            CliError::ApiError(format!("Invalid blockhash {}", blockhash.value.blockhash))
+        })
+    }
+
+    /// Submit a signed transaction and return its signature
+    pub async fn send_transaction(&self, transaction: &Transaction) -> Result<String> {
+        let bytes = bincode::serialize(transaction)
+            .map_err(|e| CliError::Other(format!("Failed to serialize transaction: {}", e)))?;
+
+        self.rpc_call(
+            "sendTransaction",
+            serde_json::json!([
+                base64::engine::general_purpose::STANDARD.encode(bytes),
+                { "encoding": "base64", "preflightCommitment": "confirmed" },
+            ]),
+        )
+        .await
+    }
+
     /// Get RPC provider information
     pub async fn get_rpc_info(&self) -> Result<RpcProviderInfo> {
+        self.ensure_cluster_served()?;
         let url = format!("{}/rpc/info", self.base_url);
         let headers = self.build_headers()?;
 
@@ -0,0 +1,335 @@
+use crate::cluster::Cluster;
+use crate::error::{CliError, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const APP_NAME: &str = "solana-dev-tools";
+const STATS_FILE: &str = "stats.json";
+
+/// Methods returning data that can change, cached only for the configured TTL
+const MUTABLE_READS: &[&str] = &[
+    "getAccountInfo",
+    "getBalance",
+    "getMultipleAccounts",
+    "getProgramAccounts",
+    "getSignaturesForAddress",
+    "getTokenAccountBalance",
+    "getTokenAccountsByOwner",
+    "getTokenSupply",
+];
+
+/// How long a cached response stays valid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheLifetime {
+    /// Finalized data that can never change
+    Immutable,
+    /// Mutable data that expires after the configured TTL
+    Ttl,
+}
+
+impl CacheLifetime {
+    /// Decide whether (and for how long) a request may be cached
+    pub fn for_request(method: &str, params: Option<&serde_json::Value>) -> Option<Self> {
+        match method {
+            // Transactions and blocks are immutable once finalized; the RPC
+            // default commitment is finalized when none is given
+            "getTransaction" | "getBlock" => match request_commitment(params) {
+                None | Some("finalized") => Some(CacheLifetime::Immutable),
+                Some(_) => Some(CacheLifetime::Ttl),
+            },
+            m if MUTABLE_READS.contains(&m) => Some(CacheLifetime::Ttl),
+            _ => None,
+        }
+    }
+}
+
+/// Find the commitment in the trailing config object of a params array
+fn request_commitment(params: Option<&serde_json::Value>) -> Option<&str> {
+    params?
+        .as_array()?
+        .iter()
+        .rev()
+        .find_map(|param| param.get("commitment")?.as_str())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    cluster: Cluster,
+    method: String,
+    params: Option<serde_json::Value>,
+    stored_at: u64,
+    /// `None` for immutable entries
+    expires_at: Option<u64>,
+    result: serde_json::Value,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheCounters {
+    hits: u64,
+    misses: u64,
+}
+
+/// Summary of the on-disk cache contents
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub immutable: usize,
+    pub expired: usize,
+    pub size_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// On-disk cache of JSON-RPC results keyed by cluster, method and params
+#[derive(Debug, Clone)]
+pub struct RpcCache {
+    dir: PathBuf,
+    ttl: Duration,
+    cluster: Cluster,
+}
+
+impl RpcCache {
+    /// Open the cache for a cluster in the user's cache directory
+    pub fn open(ttl: Duration, cluster: Cluster) -> Result<Self> {
+        Ok(Self {
+            dir: Self::default_dir()?,
+            ttl,
+            cluster,
+        })
+    }
+
+    /// Directory holding cached responses
+    pub fn default_dir() -> Result<PathBuf> {
+        dirs::cache_dir()
+            .map(|dir| dir.join(APP_NAME).join("rpc"))
+            .ok_or_else(|| CliError::ConfigError("Could not determine cache directory".to_string()))
+    }
+
+    /// Get the cache directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Look up a cached result, counting the hit or miss
+    pub fn get(
+        &self,
+        method: &str,
+        params: Option<&serde_json::Value>,
+    ) -> Option<serde_json::Value> {
+        CacheLifetime::for_request(method, params)?;
+
+        let result = self.read_live(&self.entry_path(method, params));
+
+        self.record(result.is_some());
+        result
+    }
+
+    /// Look up a value stored with [`RpcCache::put_until`]
+    ///
+    /// These lookups are not RPC reads, so they don't count towards the hit rate.
+    pub fn get_stored(
+        &self,
+        key: &str,
+        params: Option<&serde_json::Value>,
+    ) -> Option<serde_json::Value> {
+        self.read_live(&self.entry_path(key, params))
+    }
+
+    /// Store a value that isn't an RPC response, e.g. an API response, until
+    /// `expires_at` (Unix seconds) regardless of the configured TTL
+    pub fn put_until(
+        &self,
+        key: &str,
+        params: Option<&serde_json::Value>,
+        result: &serde_json::Value,
+        expires_at: u64,
+    ) -> Result<()> {
+        let entry = CacheEntry {
+            cluster: self.cluster,
+            method: key.to_string(),
+            params: params.cloned(),
+            stored_at: now(),
+            expires_at: Some(expires_at),
+            result: result.clone(),
+        };
+
+        self.write(&self.entry_path(key, params), &serde_json::to_vec(&entry)?)
+    }
+
+    /// Remove a stored value, if present
+    pub fn remove(&self, key: &str, params: Option<&serde_json::Value>) -> Result<()> {
+        let path = self.entry_path(key, params);
+        match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(cache_error(&path, e)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Store a result if the request is cacheable
+    pub fn put(
+        &self,
+        method: &str,
+        params: Option<&serde_json::Value>,
+        result: &serde_json::Value,
+    ) -> Result<()> {
+        let Some(lifetime) = CacheLifetime::for_request(method, params) else {
+            return Ok(());
+        };
+
+        // Null results (e.g. a transaction not yet visible) may change later
+        if result.is_null() {
+            return Ok(());
+        }
+
+        let stored_at = now();
+        let entry = CacheEntry {
+            cluster: self.cluster,
+            method: method.to_string(),
+            params: params.cloned(),
+            stored_at,
+            expires_at: match lifetime {
+                CacheLifetime::Immutable => None,
+                CacheLifetime::Ttl => Some(stored_at + self.ttl.as_secs()),
+            },
+            result: result.clone(),
+        };
+
+        self.write(
+            &self.entry_path(method, params),
+            &serde_json::to_vec(&entry)?,
+        )
+    }
+
+    /// Collect statistics about cached entries
+    pub fn stats(&self) -> Result<CacheStats> {
+        let counters = self.read_counters();
+        let mut stats = CacheStats {
+            hits: counters.hits,
+            misses: counters.misses,
+            ..CacheStats::default()
+        };
+
+        let now = now();
+        for path in self.entry_paths()? {
+            let Some(entry) = self.read_entry(&path) else {
+                continue;
+            };
+
+            stats.entries += 1;
+            stats.size_bytes += std::fs::metadata(&path)
+                .map(|m| m.len())
+                .unwrap_or_default();
+            match entry.expires_at {
+                None => stats.immutable += 1,
+                Some(expires_at) if expires_at <= now => stats.expired += 1,
+                Some(_) => {}
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Remove all cached entries and reset counters, returning the number removed
+    pub fn clear(&self) -> Result<usize> {
+        let paths = self.entry_paths()?;
+        for path in &paths {
+            std::fs::remove_file(path).map_err(|e| cache_error(path, e))?;
+        }
+
+        let stats_path = self.dir.join(STATS_FILE);
+        if stats_path.exists() {
+            std::fs::remove_file(&stats_path).map_err(|e| cache_error(&stats_path, e))?;
+        }
+
+        Ok(paths.len())
+    }
+
+    fn entry_path(&self, method: &str, params: Option<&serde_json::Value>) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(self.cluster.as_str().as_bytes());
+        hasher.update(b"\n");
+        hasher.update(method.as_bytes());
+        hasher.update(b"\n");
+        // serde_json sorts object keys, so equal params serialize identically
+        hasher.update(params.map(|p| p.to_string()).unwrap_or_default().as_bytes());
+
+        let key: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        self.dir.join(format!("{}.json", key))
+    }
+
+    fn entry_paths(&self) -> Result<Vec<PathBuf>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = std::fs::read_dir(&self.dir).map_err(|e| cache_error(&self.dir, e))?;
+
+        Ok(entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "json")
+                    && path.file_name().is_some_and(|name| name != STATS_FILE)
+            })
+            .collect())
+    }
+
+    fn read_entry(&self, path: &Path) -> Option<CacheEntry> {
+        let bytes = std::fs::read(path).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Read an entry's result unless it has expired
+    fn read_live(&self, path: &Path) -> Option<serde_json::Value> {
+        let entry = self.read_entry(path)?;
+        match entry.expires_at {
+            Some(expires_at) if expires_at <= now() => None,
+            _ => Some(entry.result),
+        }
+    }
+
+    fn read_counters(&self) -> CacheCounters {
+        std::fs::read(self.dir.join(STATS_FILE))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    /// Update hit/miss counters (best effort)
+    fn record(&self, hit: bool) {
+        let mut counters = self.read_counters();
+        if hit {
+            counters.hits += 1;
+        } else {
+            counters.misses += 1;
+        }
+
+        if let Ok(bytes) = serde_json::to_vec(&counters) {
+            if let Err(e) = self.write(&self.dir.join(STATS_FILE), &bytes) {
+                tracing::debug!("Failed to update cache stats: {}", e);
+            }
+        }
+    }
+
+    fn write(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| cache_error(&self.dir, e))?;
+        std::fs::write(path, bytes).map_err(|e| cache_error(path, e))
+    }
+}
+
+fn cache_error(path: &Path, error: std::io::Error) -> CliError {
+    CliError::Other(format!("Cache error at {}: {}", path.display(), error))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
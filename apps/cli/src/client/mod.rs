@@ -1,3 +1,7 @@
 pub mod api;
+pub mod cache;
+pub mod pubsub;
 
 pub use api::ApiClient;
+pub use cache::RpcCache;
+pub use pubsub::PubsubClient;
@@ -0,0 +1,156 @@
+use crate::error::{CliError, Result};
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// A notification pushed by the server for an active subscription
+#[derive(Debug, Deserialize)]
+pub struct PubsubNotification {
+    pub subscription: u64,
+    pub result: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct PubsubMessage {
+    id: Option<u64>,
+    result: Option<serde_json::Value>,
+    error: Option<PubsubError>,
+    params: Option<PubsubNotification>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PubsubError {
+    code: i64,
+    message: String,
+}
+
+/// Websocket client for Solana JSON-RPC pubsub subscriptions
+pub struct PubsubClient {
+    url: String,
+}
+
+impl PubsubClient {
+    /// Create a new pubsub client for the given websocket URL
+    pub fn new(url: String) -> Self {
+        Self { url }
+    }
+
+    /// Get the websocket URL
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Open a websocket connection
+    pub async fn connect(&self) -> Result<PubsubConnection> {
+        let request = self
+            .url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| CliError::InvalidInput(format!("Invalid websocket URL: {}", e)))?;
+
+        let (stream, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| CliError::WebSocketError(e.to_string()))?;
+
+        Ok(PubsubConnection {
+            stream,
+            next_id: 1,
+            pending: VecDeque::new(),
+        })
+    }
+}
+
+/// An open pubsub websocket connection
+pub struct PubsubConnection {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    next_id: u64,
+    pending: VecDeque<PubsubNotification>,
+}
+
+impl PubsubConnection {
+    /// Send a subscription request and wait for its subscription id
+    pub async fn subscribe(&mut self, method: &str, params: serde_json::Value) -> Result<u64> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+
+        self.stream
+            .send(Message::Text(request.to_string()))
+            .await
+            .map_err(|e| CliError::WebSocketError(e.to_string()))?;
+
+        // Notifications for earlier subscriptions may arrive before our reply
+        loop {
+            let message = self.read_message().await?.ok_or_else(|| {
+                CliError::WebSocketError("Connection closed during subscribe".to_string())
+            })?;
+
+            if let Some(notification) = message.params {
+                self.pending.push_back(notification);
+                continue;
+            }
+
+            if message.id != Some(id) {
+                continue;
+            }
+
+            if let Some(error) = message.error {
+                return Err(CliError::ApiError(format!(
+                    "{} failed: [{}] {}",
+                    method, error.code, error.message
+                )));
+            }
+
+            return message
+                .result
+                .and_then(|value| value.as_u64())
+                .ok_or_else(|| {
+                    CliError::ApiError(format!("{} returned no subscription id", method))
+                });
+        }
+    }
+
+    /// Wait for the next notification, or `None` once the server closes the connection
+    pub async fn next_notification(&mut self) -> Result<Option<PubsubNotification>> {
+        if let Some(notification) = self.pending.pop_front() {
+            return Ok(Some(notification));
+        }
+
+        while let Some(message) = self.read_message().await? {
+            if let Some(notification) = message.params {
+                return Ok(Some(notification));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Read the next JSON-RPC message, skipping control frames
+    async fn read_message(&mut self) -> Result<Option<PubsubMessage>> {
+        while let Some(frame) = self.stream.next().await {
+            let frame = frame.map_err(|e| CliError::WebSocketError(e.to_string()))?;
+
+            let text = match frame {
+                Message::Text(text) => text,
+                Message::Binary(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Message::Close(_) => return Ok(None),
+                _ => continue,
+            };
+
+            let message: PubsubMessage = serde_json::from_str(&text)?;
+            return Ok(Some(message));
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,179 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Solana cluster targeted by RPC, airdrop and deploy requests
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Cluster {
+    #[default]
+    Devnet,
+    Testnet,
+    MainnetBeta,
+}
+
+impl Cluster {
+    /// Cluster moniker as used by the API and the Solana tooling
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Cluster::Devnet => "devnet",
+            Cluster::Testnet => "testnet",
+            Cluster::MainnetBeta => "mainnet-beta",
+        }
+    }
+
+    /// Whether the API's RPC proxy and faucet are connected to this cluster
+    ///
+    /// The API runs against a single devnet RPC and takes no cluster parameter,
+    /// so other clusters are refused rather than silently answered from devnet.
+    pub fn served_by_api(&self) -> bool {
+        matches!(self, Cluster::Devnet)
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Cluster as ValueEnum>::from_str(s, true)
+            .map_err(|_| "Invalid cluster. Use: devnet, testnet, or mainnet-beta".to_string())
+    }
+}
+
+/// Block explorer used for transaction and account links
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Explorer {
+    #[default]
+    Solscan,
+    SolanaExplorer,
+    SolanaFm,
+}
+
+impl Explorer {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Explorer::Solscan => "solscan",
+            Explorer::SolanaExplorer => "solana-explorer",
+            Explorer::SolanaFm => "solana-fm",
+        }
+    }
+
+    /// Human-readable explorer name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Explorer::Solscan => "Solscan",
+            Explorer::SolanaExplorer => "Solana Explorer",
+            Explorer::SolanaFm => "SolanaFM",
+        }
+    }
+
+    /// Link to a transaction
+    pub fn tx_url(&self, signature: &str, cluster: Cluster) -> String {
+        self.url("tx", signature, cluster)
+    }
+
+    /// Link to an account or program
+    pub fn address_url(&self, address: &str, cluster: Cluster) -> String {
+        let path = match self {
+            Explorer::Solscan => "account",
+            Explorer::SolanaExplorer => "address",
+            Explorer::SolanaFm => "address",
+        };
+        self.url(path, address, cluster)
+    }
+
+    fn url(&self, path: &str, id: &str, cluster: Cluster) -> String {
+        let base = match self {
+            Explorer::Solscan => "https://solscan.io",
+            Explorer::SolanaExplorer => "https://explorer.solana.com",
+            Explorer::SolanaFm => "https://solana.fm",
+        };
+
+        match self.cluster_param(cluster) {
+            Some(param) => format!("{}/{}/{}?cluster={}", base, path, id, param),
+            None => format!("{}/{}/{}", base, path, id),
+        }
+    }
+
+    /// Value of the `cluster` query parameter (mainnet is each explorer's default)
+    fn cluster_param(&self, cluster: Cluster) -> Option<&'static str> {
+        match (self, cluster) {
+            (_, Cluster::MainnetBeta) => None,
+            (Explorer::SolanaFm, Cluster::Devnet) => Some("devnet-solana"),
+            (Explorer::SolanaFm, Cluster::Testnet) => Some("testnet-solana"),
+            (_, cluster) => Some(cluster.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for Explorer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Explorer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Explorer as ValueEnum>::from_str(s, true).map_err(|_| {
+            "Invalid explorer. Use: solscan, solana-explorer, or solana-fm".to_string()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_cluster_monikers() {
+        assert_eq!("devnet".parse(), Ok(Cluster::Devnet));
+        assert_eq!("Testnet".parse(), Ok(Cluster::Testnet));
+        assert_eq!("mainnet-beta".parse(), Ok(Cluster::MainnetBeta));
+        assert!("mainnet".parse::<Cluster>().is_err());
+        assert!("localnet".parse::<Cluster>().is_err());
+    }
+
+    #[test]
+    fn cluster_round_trips_through_display_and_serde() {
+        for cluster in [Cluster::Devnet, Cluster::Testnet, Cluster::MainnetBeta] {
+            assert_eq!(cluster.to_string().parse(), Ok(cluster));
+
+            let json = serde_json::to_string(&cluster).unwrap();
+            assert_eq!(json, format!("\"{}\"", cluster));
+            assert_eq!(serde_json::from_str::<Cluster>(&json).unwrap(), cluster);
+        }
+    }
+
+    #[test]
+    fn parses_explorers() {
+        assert_eq!("solana-fm".parse(), Ok(Explorer::SolanaFm));
+        assert_eq!("Solscan".parse(), Ok(Explorer::Solscan));
+        assert!("etherscan".parse::<Explorer>().is_err());
+    }
+
+    #[test]
+    fn explorer_links_carry_the_cluster() {
+        assert_eq!(
+            Explorer::Solscan.tx_url("sig", Cluster::Devnet),
+            "https://solscan.io/tx/sig?cluster=devnet"
+        );
+        assert_eq!(
+            Explorer::SolanaExplorer.address_url("addr", Cluster::MainnetBeta),
+            "https://explorer.solana.com/address/addr"
+        );
+        assert_eq!(
+            Explorer::SolanaFm.address_url("addr", Cluster::Testnet),
+            "https://solana.fm/address/addr?cluster=testnet-solana"
+        );
+    }
+}
@@ -1,35 +1,134 @@
+use crate::address::{parse_address, resolve_address};
+use crate::amount::Lamports;
 use crate::auth::CredentialsStore;
-use crate::cli::AirdropCommands;
+use crate::cli::{AirdropCommands, HistoryFilter};
+use crate::client::api::{
+    AirdropRecord, AirdropResponse, Commitment, DailyUsageRecord, UsageHistoryResponse,
+};
 use crate::client::ApiClient;
+use crate::cluster::Cluster;
 use crate::config::manager::ConfigManager;
-use crate::error::Result;
+use crate::error::{CliError, Result};
+use crate::export::{export_records, fetch_all, ExportArgs, Page};
+use crate::quota::{format_duration, time_until_reset, AirdropQuota, QuotaExceeded};
+use crate::transaction::wait_for_confirmation;
+use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
 use comfy_table::{Cell, Color, Table};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-pub async fn handle_airdrop_command(command: AirdropCommands, api_url: &str) -> Result<()> {
+const BATCH_STATUS_FUNDED: &str = "funded";
+const BATCH_STATUS_FAILED: &str = "failed";
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(2);
+
+pub async fn handle_airdrop_command(
+    command: AirdropCommands,
+    api_url: &str,
+    cluster: Cluster,
+) -> Result<()> {
     match command {
-        AirdropCommands::Request { amount, recipient } => {
-            request_airdrop(api_url, amount, &recipient).await
+        AirdropCommands::Request {
+            amount,
+            recipient,
+            wait,
+            commitment,
+            timeout,
+            force,
+        } => {
+            let wait = wait.then(|| (commitment, Duration::from_secs(timeout)));
+            request_airdrop(api_url, cluster, amount, recipient.as_deref(), wait, force).await
+        }
+        AirdropCommands::Ensure {
+            recipient,
+            min,
+            commitment,
+            timeout,
+        } => {
+            ensure_balance(
+                api_url,
+                cluster,
+                recipient.as_deref(),
+                min,
+                commitment,
+                Duration::from_secs(timeout),
+            )
+            .await
         }
-        AirdropCommands::History { limit, offset } => {
-            show_history(api_url, limit, offset).await
+        AirdropCommands::Batch {
+            file,
+            report,
+            resume,
+            concurrency,
+            max_retries,
+        } => {
+            let options = BatchOptions {
+                report: report.unwrap_or_else(|| default_report_path(&file)),
+                resume,
+                concurrency,
+                max_retries,
+            };
+            batch_airdrop(api_url, cluster, &file, options).await
+        }
+        AirdropCommands::History {
+            limit,
+            offset,
+            all,
+            filter,
+            export,
+        } => show_history(api_url, limit, offset, all, filter, export).await,
+        AirdropCommands::Usage { history, days } => {
+            show_usage(api_url, history.then_some(days)).await
         }
-        AirdropCommands::Usage => show_usage(api_url).await,
     }
 }
 
-async fn request_airdrop(api_url: &str, amount: f64, recipient: &str) -> Result<()> {
-    // Load credentials
-    let credentials = CredentialsStore::load()?;
+async fn request_airdrop(
+    api_url: &str,
+    cluster: Cluster,
+    amount: Lamports,
+    recipient: Option<&str>,
+    wait: Option<(Commitment, Duration)>,
+    force: bool,
+) -> Result<()> {
+    if !cluster.served_by_api() {
+        return Err(CliError::InvalidInput(format!(
+            "Airdrops are only available on devnet, not {}",
+            cluster
+        )));
+    }
 
-    // Load config for timeout
+    // Load config for timeout and default keypair
     let config = ConfigManager::new()?.load()?;
     let timeout = Duration::from_secs(config.api.timeout_seconds);
 
+    // Validate the recipient locally before spending a request
+    let recipient = resolve_address(recipient, &config)?.to_string();
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+    let user_id = credentials.user_id.clone();
+
     // Create API client
     let client = ApiClient::new(api_url.to_string(), timeout)?
-        .with_credentials(credentials);
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    // Check the remaining quota before spending a request
+    let mut amount = amount;
+    let mut quota = None;
+    if !force {
+        quota = AirdropQuota::load(&client, &config, cluster, &user_id, api_url).await?;
+        if let Some(ref quota) = quota {
+            if let Err(exceeded) = quota.check(amount) {
+                amount = offer_max_allowed(quota, amount, exceeded)?;
+            }
+        }
+    }
 
     // Show spinner
     let spinner = indicatif::ProgressBar::new_spinner();
@@ -37,28 +136,328 @@ async fn request_airdrop(api_url: &str, amount: f64, recipient: &str) -> Result<
     spinner.enable_steady_tick(Duration::from_millis(100));
 
     // Request airdrop
-    let response = client.request_airdrop(amount, recipient).await?;
+    let response = match client.request_airdrop(amount, &recipient).await {
+        Ok(response) => response,
+        Err(e) => {
+            spinner.finish_and_clear();
+
+            // The cached quota is out of date if the API disagrees
+            if let (Some(quota), CliError::ApiResponseError { status: 429, .. }) = (&quota, &e) {
+                quota.invalidate();
+            }
+            return Err(e);
+        }
+    };
 
     spinner.finish_and_clear();
 
+    if let Some(ref mut quota) = quota {
+        quota.record(amount);
+    }
+
     // Display result
     println!();
     println!("{}", "✓ Airdrop requested successfully".green().bold());
     println!();
     println!("  Transaction: {}", response.signature.bright_blue());
-    println!("  Amount:      {} SOL", response.amount.to_string().bright_yellow());
+    println!(
+        "  Amount:      {}",
+        response.amount.to_string().bright_yellow()
+    );
     println!("  Recipient:   {}", response.recipient.bright_cyan());
+    println!("  Cluster:     {}", cluster.to_string().bright_cyan());
     println!();
-    println!("  View on Solscan:");
-    println!("  https://solscan.io/tx/{}?cluster=devnet", response.signature);
+    println!("  View on {}:", config.network.explorer.name());
+    println!(
+        "  {}",
+        config.network.explorer.tx_url(&response.signature, cluster)
+    );
     println!();
 
+    if let Some((commitment, timeout)) = wait {
+        let status =
+            wait_for_confirmation(&client, &response.signature, commitment, timeout).await?;
+        let balance = client.get_balance(&response.recipient, commitment).await?;
+
+        println!(
+            "{}",
+            format!("✓ Transaction {} in slot {}", commitment, status.slot)
+                .green()
+                .bold()
+        );
+        println!();
+        println!(
+            "  Recipient balance: {}",
+            balance.to_string().bright_yellow()
+        );
+        println!();
+    }
+
     Ok(())
 }
 
-async fn show_history(api_url: &str, limit: u32, offset: u32) -> Result<()> {
+/// Explain why `amount` exceeds the quota and offer to request the largest allowed amount
+fn offer_max_allowed(
+    quota: &AirdropQuota,
+    amount: Lamports,
+    exceeded: QuotaExceeded,
+) -> Result<Lamports> {
+    let message = quota.describe(amount, exceeded);
+    let max = quota.max_allowed();
+
+    if max == Lamports::ZERO || !std::io::stdin().is_terminal() {
+        let hint = if max == Lamports::ZERO {
+            "Pass --force to send the request anyway".to_string()
+        } else {
+            format!(
+                "Request {} or less, or pass --force to send the request anyway",
+                max
+            )
+        };
+        return Err(CliError::InvalidInput(format!("{}. {}", message, hint)));
+    }
+
+    eprintln!("{} {}", "⚠".yellow(), message);
+    print!(
+        "Request {} instead? [y/N] ",
+        max.to_string().bright_yellow()
+    );
+    std::io::stdout().flush().ok();
+
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .map_err(|e| CliError::Other(format!("Failed to read answer: {}", e)))?;
+
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(max),
+        _ => Err(CliError::Cancelled),
+    }
+}
+
+async fn ensure_balance(
+    api_url: &str,
+    cluster: Cluster,
+    recipient: Option<&str>,
+    min: Lamports,
+    commitment: Commitment,
+    timeout: Duration,
+) -> Result<()> {
+    if !cluster.served_by_api() {
+        return Err(CliError::InvalidInput(format!(
+            "Airdrops are only available on devnet, not {}",
+            cluster
+        )));
+    }
+
+    // Load config for timeout and default keypair
+    let config = ConfigManager::new()?.load()?;
+    let api_timeout = Duration::from_secs(config.api.timeout_seconds);
+
+    let recipient = resolve_address(recipient, &config)?.to_string();
+
     // Load credentials
     let credentials = CredentialsStore::load()?;
+    let user_id = credentials.user_id.clone();
+
+    // Create API client
+    let client = ApiClient::new(api_url.to_string(), api_timeout)?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    let balance = client.get_balance(&recipient, commitment).await?;
+
+    if balance >= min {
+        println!(
+            "{} {} holds {} (minimum {})",
+            "✓".green(),
+            recipient.bright_cyan(),
+            balance.to_string().bright_yellow(),
+            min
+        );
+        return Ok(());
+    }
+
+    let shortfall = min.saturating_sub(balance);
+    let mut quota = AirdropQuota::load(&client, &config, cluster, &user_id, api_url).await?;
+    let requests = plan_top_up(quota.as_ref(), shortfall)?;
+
+    println!();
+    println!(
+        "Topping up {} from {} to {} ({} in {} airdrop{})",
+        recipient.bright_cyan(),
+        balance,
+        min,
+        shortfall.to_string().bright_yellow(),
+        requests.len(),
+        if requests.len() == 1 { "" } else { "s" }
+    );
+    println!();
+
+    for (i, amount) in requests.iter().enumerate() {
+        let spinner = indicatif::ProgressBar::new_spinner();
+        spinner.set_message(format!(
+            "Requesting airdrop {}/{} of {}...",
+            i + 1,
+            requests.len(),
+            amount
+        ));
+        spinner.enable_steady_tick(Duration::from_millis(100));
+
+        let response = match client.request_airdrop(*amount, &recipient).await {
+            Ok(response) => response,
+            Err(e) => {
+                spinner.finish_and_clear();
+                if let Some(ref quota) = quota {
+                    quota.invalidate();
+                }
+                return Err(e);
+            }
+        };
+        spinner.finish_and_clear();
+
+        if let Some(ref mut quota) = quota {
+            quota.record(*amount);
+        }
+
+        let status =
+            wait_for_confirmation(&client, &response.signature, commitment, timeout).await?;
+
+        println!(
+            "  {} {} in slot {}: {}",
+            "✓".green(),
+            amount.to_string().bright_yellow(),
+            status.slot,
+            response.signature.bright_blue()
+        );
+    }
+
+    let balance = client.get_balance(&recipient, commitment).await?;
+
+    println!();
+    println!("{}", format!("✓ Balance is now {}", balance).green().bold());
+    println!();
+
+    Ok(())
+}
+
+/// Split a shortfall into airdrops that fit the per-request maximum and daily limits
+fn plan_top_up(quota: Option<&AirdropQuota>, shortfall: Lamports) -> Result<Vec<Lamports>> {
+    let Some(quota) = quota else {
+        return Ok(vec![shortfall]);
+    };
+
+    if quota.max_per_request() == Lamports::ZERO {
+        return Err(CliError::Other(
+            "Airdrops are currently disabled (maximum per request is 0 SOL)".to_string(),
+        ));
+    }
+
+    let mut requests = Vec::new();
+    let mut remaining = shortfall;
+    while remaining > Lamports::ZERO {
+        let amount = remaining.min(quota.max_per_request());
+        requests.push(amount);
+        remaining = remaining.saturating_sub(amount);
+    }
+
+    let remaining_requests = quota.remaining_requests() as usize;
+    if requests.len() > remaining_requests || shortfall > quota.remaining_volume() {
+        return Err(CliError::InvalidInput(format!(
+            "Topping up needs {} in {} requests, but only {} in {} requests remain today",
+            shortfall,
+            requests.len(),
+            quota.remaining_volume(),
+            remaining_requests
+        )));
+    }
+
+    Ok(requests)
+}
+
+struct BatchOptions {
+    report: PathBuf,
+    resume: bool,
+    concurrency: usize,
+    max_retries: u32,
+}
+
+/// A validated row of a batch file
+struct BatchRow {
+    address: String,
+    amount: Lamports,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchFileEntry {
+    address: String,
+    #[serde(with = "crate::amount::sol_exact")]
+    amount: Lamports,
+}
+
+/// A row of the batch report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BatchResult {
+    row: usize,
+    address: String,
+    amount: String,
+    status: String,
+    signature: String,
+    error: String,
+}
+
+async fn batch_airdrop(
+    api_url: &str,
+    cluster: Cluster,
+    file: &Path,
+    options: BatchOptions,
+) -> Result<()> {
+    if !cluster.served_by_api() {
+        return Err(CliError::InvalidInput(format!(
+            "Airdrops are only available on devnet, not {}",
+            cluster
+        )));
+    }
+
+    if options.concurrency == 0 {
+        return Err(CliError::InvalidInput(
+            "Concurrency must be at least 1".to_string(),
+        ));
+    }
+
+    // Validate every row before contacting the API
+    let rows = read_batch_file(file)?;
+    if rows.is_empty() {
+        println!("{}", "No rows found in batch file.".yellow());
+        return Ok(());
+    }
+
+    // Carry over rows already funded by a previous run
+    let mut results: Vec<Option<BatchResult>> = vec![None; rows.len()];
+    if options.report.exists() {
+        if !options.resume {
+            return Err(CliError::InvalidInput(format!(
+                "Report {} already exists. Use --resume to continue it or --report to choose another file",
+                options.report.display()
+            )));
+        }
+        carry_over_funded(&options.report, &rows, &mut results)?;
+    }
+
+    let pending: Vec<usize> = (0..rows.len()).filter(|&i| results[i].is_none()).collect();
+    let skipped = rows.len() - pending.len();
+
+    if pending.is_empty() {
+        println!(
+            "{}",
+            format!("All {} rows are already funded.", rows.len()).green()
+        );
+        return Ok(());
+    }
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+    let user_id = credentials.user_id.clone();
 
     // Load config for timeout
     let config = ConfigManager::new()?.load()?;
@@ -66,7 +465,374 @@ async fn show_history(api_url: &str, limit: u32, offset: u32) -> Result<()> {
 
     // Create API client
     let client = ApiClient::new(api_url.to_string(), timeout)?
-        .with_credentials(credentials);
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    // Check the batch fits in today's remaining limits
+    let pending_rows: Vec<&BatchRow> = pending.iter().map(|&i| &rows[i]).collect();
+    let mut quota = AirdropQuota::load(&client, &config, cluster, &user_id, api_url).await?;
+    if let Some(ref quota) = quota {
+        check_batch_limits(quota, &pending_rows)?;
+    }
+
+    println!();
+    println!(
+        "Funding {} wallets on {}{}",
+        pending.len(),
+        cluster,
+        if skipped > 0 {
+            format!(" ({} already funded)", skipped)
+        } else {
+            String::new()
+        }
+    );
+    println!();
+
+    let progress = indicatif::ProgressBar::new(pending.len() as u64);
+    progress.set_style(
+        indicatif::ProgressStyle::default_bar()
+            .template("{bar:40.cyan/blue} {pos}/{len} {msg}")
+            .unwrap(),
+    );
+
+    let mut requests = futures_util::stream::iter(pending.iter().map(|&i| {
+        let client = &client;
+        let row = &rows[i];
+        let max_retries = options.max_retries;
+        async move {
+            let outcome = request_with_backoff(client, row.amount, &row.address, max_retries).await;
+            (i, outcome)
+        }
+    }))
+    .buffer_unordered(options.concurrency);
+
+    while let Some((i, outcome)) = requests.next().await {
+        let row = &rows[i];
+        let (status, signature, error) = match outcome {
+            Ok(response) => {
+                if let Some(ref mut quota) = quota {
+                    quota.record(row.amount);
+                }
+                (BATCH_STATUS_FUNDED, response.signature, String::new())
+            }
+            Err(e) => (BATCH_STATUS_FAILED, String::new(), e.to_string()),
+        };
+
+        results[i] = Some(BatchResult {
+            row: i + 1,
+            address: row.address.clone(),
+            amount: row.amount.to_sol_string(),
+            status: status.to_string(),
+            signature,
+            error,
+        });
+
+        // Rewrite the report after every row so an interrupted batch can resume
+        write_batch_report(&options.report, &results)?;
+        progress.inc(1);
+    }
+
+    progress.finish_and_clear();
+
+    let failed: Vec<&BatchResult> = results
+        .iter()
+        .flatten()
+        .filter(|r| r.status == BATCH_STATUS_FAILED)
+        .collect();
+
+    // Failed requests may or may not have counted against the quota
+    if let (Some(quota), false) = (&quota, failed.is_empty()) {
+        quota.invalidate();
+    }
+    let funded: Lamports = pending
+        .iter()
+        .filter(|&&i| {
+            results[i]
+                .as_ref()
+                .is_some_and(|r| r.status == BATCH_STATUS_FUNDED)
+        })
+        .fold(Lamports::ZERO, |total, &i| {
+            total.checked_add(rows[i].amount).unwrap_or(total)
+        });
+
+    println!("{}", "Batch complete".bold());
+    println!();
+    println!(
+        "  Funded:  {} ({})",
+        (pending.len() - failed.len()).to_string().green(),
+        funded.to_string().bright_yellow()
+    );
+    println!("  Failed:  {}", failed.len().to_string().red());
+    println!("  Skipped: {}", skipped);
+    println!(
+        "  Report:  {}",
+        options.report.display().to_string().bright_black()
+    );
+    println!();
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Row").fg(Color::Cyan),
+        Cell::new("Address").fg(Color::Cyan),
+        Cell::new("Amount").fg(Color::Cyan),
+        Cell::new("Error").fg(Color::Cyan),
+    ]);
+    for result in &failed {
+        table.add_row(vec![
+            Cell::new(result.row),
+            Cell::new(&result.address),
+            Cell::new(format!("{} SOL", result.amount)),
+            Cell::new(&result.error).fg(Color::Red),
+        ]);
+    }
+    println!("{}", table);
+    println!();
+
+    Err(CliError::Other(format!(
+        "{} of {} airdrops failed. Re-run with --resume to retry them",
+        failed.len(),
+        pending.len()
+    )))
+}
+
+/// Parse a CSV or JSON batch file, validating addresses and amounts
+///
+/// Rows are numbered from 1 counting data rows only, the same numbering the
+/// batch report uses, so a CSV header or comment line never shifts them.
+fn read_batch_file(path: &Path) -> Result<Vec<BatchRow>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| CliError::InvalidInput(format!("Cannot read {}: {}", path.display(), e)))?;
+
+    let mut rows = Vec::new();
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+    {
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&contents)?;
+        for (i, entry) in entries.into_iter().enumerate() {
+            let entry: BatchFileEntry =
+                serde_json::from_value(entry).map_err(|e| row_error(i + 1, e))?;
+            rows.push(BatchRow {
+                address: parse_row_address(i + 1, &entry.address)?,
+                amount: entry.amount,
+            });
+        }
+    } else {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .trim(csv::Trim::All)
+            .comment(Some(b'#'))
+            .from_reader(contents.as_bytes());
+
+        let mut has_header = false;
+        for (i, record) in reader.records().enumerate() {
+            let row = if has_header { i } else { i + 1 };
+            let record = record.map_err(|e| row_error(row, e))?;
+
+            // Optional header row
+            if i == 0
+                && record
+                    .get(0)
+                    .is_some_and(|f| f.eq_ignore_ascii_case("address"))
+            {
+                has_header = true;
+                continue;
+            }
+
+            match (record.get(0), record.get(1)) {
+                (Some(address), Some(amount)) => rows.push(BatchRow {
+                    address: parse_row_address(row, address)?,
+                    amount: amount.parse().map_err(|e| row_error(row, e))?,
+                }),
+                _ => return Err(row_error(row, "expected 'address,amount'")),
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+fn row_error(row: usize, error: impl std::fmt::Display) -> CliError {
+    CliError::InvalidInput(format!("Row {}: {}", row, error))
+}
+
+fn parse_row_address(row: usize, address: &str) -> Result<String> {
+    match parse_address(address) {
+        Ok(address) => Ok(address.to_string()),
+        Err(CliError::InvalidInput(message)) => Err(row_error(row, message)),
+        Err(other) => Err(other),
+    }
+}
+
+/// Mark rows that a previous report recorded as funded
+fn carry_over_funded(
+    report: &Path,
+    rows: &[BatchRow],
+    results: &mut [Option<BatchResult>],
+) -> Result<()> {
+    let mut reader = csv::Reader::from_path(report).map_err(|e| {
+        CliError::InvalidInput(format!("Cannot read report {}: {}", report.display(), e))
+    })?;
+
+    // Match on address and amount so reordered input files still resume correctly
+    let mut funded: HashMap<(String, String), Vec<BatchResult>> = HashMap::new();
+    for result in reader.deserialize::<BatchResult>() {
+        let result = result.map_err(|e| {
+            CliError::InvalidInput(format!("Invalid report {}: {}", report.display(), e))
+        })?;
+        if result.status == BATCH_STATUS_FUNDED {
+            funded
+                .entry((result.address.clone(), result.amount.clone()))
+                .or_default()
+                .push(result);
+        }
+    }
+
+    for (i, row) in rows.iter().enumerate() {
+        let key = (row.address.clone(), row.amount.to_sol_string());
+        if let Some(previous) = funded.get_mut(&key).and_then(|r| r.pop()) {
+            results[i] = Some(BatchResult {
+                row: i + 1,
+                ..previous
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Refuse batches that would exceed today's remaining airdrop limits
+fn check_batch_limits(quota: &AirdropQuota, rows: &[&BatchRow]) -> Result<()> {
+    if let Some(row) = rows.iter().find(|r| r.amount > quota.max_per_request()) {
+        return Err(CliError::InvalidInput(format!(
+            "{} for {} exceeds the maximum of {} per request",
+            row.amount,
+            row.address,
+            quota.max_per_request()
+        )));
+    }
+
+    let remaining_requests = quota.remaining_requests() as usize;
+    if rows.len() > remaining_requests {
+        return Err(CliError::InvalidInput(format!(
+            "Batch needs {} requests but only {} remain today",
+            rows.len(),
+            remaining_requests
+        )));
+    }
+
+    let total = rows
+        .iter()
+        .try_fold(Lamports::ZERO, |total, r| total.checked_add(r.amount))
+        .ok_or_else(|| CliError::InvalidInput("Batch total is too large".to_string()))?;
+    if total > quota.remaining_volume() {
+        return Err(CliError::InvalidInput(format!(
+            "Batch total of {} exceeds the {} remaining today",
+            total,
+            quota.remaining_volume()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Request an airdrop, backing off exponentially while rate limited
+async fn request_with_backoff(
+    client: &ApiClient,
+    amount: Lamports,
+    recipient: &str,
+    max_retries: u32,
+) -> Result<AirdropResponse> {
+    let mut delay = RATE_LIMIT_BACKOFF;
+    let mut attempt = 0;
+
+    loop {
+        match client.request_airdrop(amount, recipient).await {
+            Err(CliError::ApiResponseError { status: 429, .. }) if attempt < max_retries => {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            outcome => return outcome,
+        }
+    }
+}
+
+fn write_batch_report(path: &Path, results: &[Option<BatchResult>]) -> Result<()> {
+    let report_error =
+        |e: csv::Error| CliError::Other(format!("Failed to write {}: {}", path.display(), e));
+
+    let mut writer = csv::Writer::from_path(path).map_err(report_error)?;
+    for result in results.iter().flatten() {
+        writer.serialize(result).map_err(report_error)?;
+    }
+    writer
+        .flush()
+        .map_err(|e| CliError::Other(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+fn default_report_path(file: &Path) -> PathBuf {
+    let stem = file
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("airdrop-batch");
+    file.with_file_name(format!("{}.report.csv", stem))
+}
+
+/// An airdrop with full, untruncated fields for CSV/NDJSON export
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AirdropExportRow<'a> {
+    id: &'a str,
+    created_at: &'a str,
+    recipient: &'a str,
+    amount: String,
+    amount_lamports: u64,
+    status: &'a str,
+    signature: &'a str,
+    explorer_url: &'a str,
+}
+
+impl<'a> From<&'a AirdropRecord> for AirdropExportRow<'a> {
+    fn from(airdrop: &'a AirdropRecord) -> Self {
+        Self {
+            id: &airdrop.id,
+            created_at: &airdrop.created_at,
+            recipient: &airdrop.recipient,
+            amount: airdrop.amount.to_sol_string(),
+            amount_lamports: airdrop.amount.0,
+            status: &airdrop.status,
+            signature: &airdrop.signature,
+            explorer_url: &airdrop.explorer_url,
+        }
+    }
+}
+
+async fn show_history(
+    api_url: &str,
+    limit: u32,
+    offset: u32,
+    all: bool,
+    filter: HistoryFilter,
+    export: ExportArgs,
+) -> Result<()> {
+    if let Some(ref recipient) = filter.recipient {
+        parse_address(recipient)?;
+    }
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+
+    // Load config for timeout
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+
+    // Create API client
+    let client = ApiClient::new(api_url.to_string(), timeout)?.with_credentials(credentials);
 
     // Show spinner
     let spinner = indicatif::ProgressBar::new_spinner();
@@ -74,11 +840,47 @@ async fn show_history(api_url: &str, limit: u32, offset: u32) -> Result<()> {
     spinner.enable_steady_tick(Duration::from_millis(100));
 
     // Get history
-    let response = client.get_airdrop_history(limit, offset).await?;
+    let (fetched, pagination) = if all {
+        // History is newest first, so stop once a page reaches past --since
+        let airdrops = fetch_all(
+            limit,
+            offset,
+            |limit, offset| {
+                let client = &client;
+                async move {
+                    let response = client.get_airdrop_history(limit, offset).await?;
+                    Ok(Page {
+                        items: response.airdrops,
+                        has_more: response.pagination.has_more,
+                    })
+                }
+            },
+            |page| match (filter.since, page.last().and_then(created_date)) {
+                (Some(since), Some(oldest)) => oldest >= since,
+                _ => true,
+            },
+        )
+        .await?;
+        (airdrops, None)
+    } else {
+        let response = client.get_airdrop_history(limit, offset).await?;
+        (response.airdrops, Some(response.pagination))
+    };
 
     spinner.finish_and_clear();
 
-    if response.airdrops.is_empty() {
+    let fetched_count = fetched.len();
+    let airdrops: Vec<AirdropRecord> = fetched
+        .into_iter()
+        .filter(|airdrop| matches_filter(&filter, airdrop))
+        .collect();
+
+    if let Some(format) = export.export {
+        let rows: Vec<AirdropExportRow> = airdrops.iter().map(AirdropExportRow::from).collect();
+        return export_records(&rows, format, export.out.as_deref());
+    }
+
+    if airdrops.is_empty() {
         println!("{}", "No airdrops found.".yellow());
         return Ok(());
     }
@@ -93,37 +895,27 @@ async fn show_history(api_url: &str, limit: u32, offset: u32) -> Result<()> {
         Cell::new("Signature").fg(Color::Cyan),
     ]);
 
-    for airdrop in &response.airdrops {
+    for airdrop in &airdrops {
         let status_cell = match airdrop.status.as_str() {
-            "completed" => Cell::new(&airdrop.status).fg(Color::Green),
+            "completed" | "confirmed" => Cell::new(&airdrop.status).fg(Color::Green),
             "pending" => Cell::new(&airdrop.status).fg(Color::Yellow),
             "failed" => Cell::new(&airdrop.status).fg(Color::Red),
             _ => Cell::new(&airdrop.status),
         };
 
         // Format date (take just the date part)
-        let date_part = airdrop.created_at.split('T').next().unwrap_or(&airdrop.created_at);
-
-        // Truncate signature for display
-        let sig_display = if airdrop.signature.len() > 12 {
-            format!("{}...{}", &airdrop.signature[..6], &airdrop.signature[airdrop.signature.len()-6..])
-        } else {
-            airdrop.signature.clone()
-        };
-
-        // Truncate recipient for display
-        let recipient_display = if airdrop.recipient.len() > 12 {
-            format!("{}...{}", &airdrop.recipient[..6], &airdrop.recipient[airdrop.recipient.len()-6..])
-        } else {
-            airdrop.recipient.clone()
-        };
+        let date_part = airdrop
+            .created_at
+            .split('T')
+            .next()
+            .unwrap_or(&airdrop.created_at);
 
         table.add_row(vec![
             Cell::new(date_part),
-            Cell::new(format!("{} SOL", airdrop.amount)),
-            Cell::new(recipient_display),
+            Cell::new(airdrop.amount),
+            Cell::new(truncate_middle(&airdrop.recipient)),
             status_cell,
-            Cell::new(sig_display),
+            Cell::new(truncate_middle(&airdrop.signature)),
         ]);
     }
 
@@ -132,22 +924,41 @@ async fn show_history(api_url: &str, limit: u32, offset: u32) -> Result<()> {
     println!();
 
     // Show pagination info
-    println!(
-        "Showing {} - {} of {} total airdrops",
-        offset + 1,
-        offset + response.airdrops.len() as u32,
-        response.pagination.total
-    );
+    match pagination {
+        Some(pagination) => {
+            if airdrops.len() < fetched_count {
+                println!(
+                    "{} matching airdrops in {} - {} of {} total",
+                    airdrops.len(),
+                    offset + 1,
+                    offset + fetched_count as u32,
+                    pagination.total
+                );
+            } else {
+                println!(
+                    "Showing {} - {} of {} total airdrops",
+                    offset + 1,
+                    offset + fetched_count as u32,
+                    pagination.total
+                );
+            }
 
-    if response.pagination.has_more {
-        println!(
-            "{}",
-            format!(
-                "Use --offset {} to see more",
-                offset + response.airdrops.len() as u32
-            )
-            .bright_black()
-        );
+            if pagination.has_more {
+                println!(
+                    "{}",
+                    format!(
+                        "Use --offset {} to see more, or --all to fetch everything",
+                        offset + fetched_count as u32
+                    )
+                    .bright_black()
+                );
+            }
+        }
+        None => println!(
+            "Showing {} of {} fetched airdrops",
+            airdrops.len(),
+            fetched_count
+        ),
     }
 
     println!();
@@ -155,7 +966,52 @@ async fn show_history(api_url: &str, limit: u32, offset: u32) -> Result<()> {
     Ok(())
 }
 
-async fn show_usage(api_url: &str) -> Result<()> {
+fn matches_filter(filter: &HistoryFilter, airdrop: &AirdropRecord) -> bool {
+    if let Some(ref status) = filter.status {
+        if !airdrop.status.eq_ignore_ascii_case(status) {
+            return false;
+        }
+    }
+
+    if let Some(ref recipient) = filter.recipient {
+        if airdrop.recipient != *recipient {
+            return false;
+        }
+    }
+
+    if filter.since.is_some() || filter.until.is_some() {
+        let Some(date) = created_date(airdrop) else {
+            return false;
+        };
+        if filter.since.is_some_and(|since| date < since)
+            || filter.until.is_some_and(|until| date > until)
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// UTC date an airdrop was created
+fn created_date(airdrop: &AirdropRecord) -> Option<NaiveDate> {
+    airdrop
+        .created_at
+        .parse::<DateTime<Utc>>()
+        .ok()
+        .map(|created| created.date_naive())
+}
+
+/// Shorten long base58 strings for table display
+fn truncate_middle(value: &str) -> String {
+    if value.len() > 12 {
+        format!("{}...{}", &value[..6], &value[value.len() - 6..])
+    } else {
+        value.to_string()
+    }
+}
+
+async fn show_usage(api_url: &str, history_days: Option<u32>) -> Result<()> {
     // Load credentials
     let credentials = CredentialsStore::load()?;
 
@@ -164,8 +1020,7 @@ async fn show_usage(api_url: &str) -> Result<()> {
     let timeout = Duration::from_secs(config.api.timeout_seconds);
 
     // Create API client
-    let client = ApiClient::new(api_url.to_string(), timeout)?
-        .with_credentials(credentials);
+    let client = ApiClient::new(api_url.to_string(), timeout)?.with_credentials(credentials);
 
     // Show spinner
     let spinner = indicatif::ProgressBar::new_spinner();
@@ -175,21 +1030,137 @@ async fn show_usage(api_url: &str) -> Result<()> {
     // Get usage stats
     let response = client.get_airdrop_usage().await?;
 
+    // Daily limits reset at midnight UTC
+    let today = Utc::now().date_naive();
+
+    let history = match history_days {
+        Some(days) => {
+            let start = today - chrono::Days::new(u64::from(days) - 1);
+            Some(client.get_airdrop_usage_history(start, today).await?)
+        }
+        None => None,
+    };
+
     spinner.finish_and_clear();
 
+    let usage = response.usage;
+
     // Display stats
     println!();
     println!("{}", "Airdrop Usage Statistics".bold());
     println!("{}", "=".repeat(40));
     println!();
     println!("{}", "Today:".bright_cyan());
-    println!("  Requests: {}", response.usage.today.requests);
-    println!("  Amount:   {} SOL", response.usage.today.amount);
+    println!("  Requests: {}", usage.today.requests);
+    println!("  Amount:   {}", usage.today.amount);
     println!();
     println!("{}", "Total:".bright_cyan());
-    println!("  Requests: {}", response.usage.total.requests);
-    println!("  Amount:   {} SOL", response.usage.total.amount);
+    println!("  Requests: {}", usage.total.requests);
+    println!("  Amount:   {}", usage.total.amount);
     println!();
 
+    if let Some(quota) = AirdropQuota::from_usage(&usage) {
+        println!("{}", "Daily Limits:".bright_cyan());
+        println!(
+            "  Requests:        {} / {} ({} remaining)",
+            usage.today.requests,
+            quota.daily_requests(),
+            quota.remaining_requests().to_string().green()
+        );
+        println!(
+            "  Volume:          {} / {} ({} remaining)",
+            usage.today.amount.to_sol_string(),
+            quota.daily_volume(),
+            quota.remaining_volume().to_string().green()
+        );
+        println!("  Max per request: {}", quota.max_per_request());
+
+        println!(
+            "  Resets in:       {} (00:00 UTC)",
+            format_duration(time_until_reset())
+        );
+        println!();
+    }
+
+    if let Some(history) = history {
+        print_usage_history(&history);
+    }
+
     Ok(())
 }
+
+/// Print a per-day table and sparkline, filling days without usage with zero
+fn print_usage_history(history: &UsageHistoryResponse) {
+    let by_date: HashMap<NaiveDate, &DailyUsageRecord> = history
+        .daily
+        .iter()
+        .filter_map(|record| Some((record.date()?, record)))
+        .collect();
+
+    let days: Vec<(NaiveDate, u32, Lamports)> = history
+        .period
+        .start
+        .iter_days()
+        .take_while(|date| *date <= history.period.end)
+        .map(|date| match by_date.get(&date) {
+            Some(record) => (date, record.requests, record.amount),
+            None => (date, 0, Lamports::ZERO),
+        })
+        .collect();
+
+    println!(
+        "{}",
+        format!(
+            "History ({} to {})",
+            history.period.start, history.period.end
+        )
+        .bold()
+    );
+    println!("{}", "=".repeat(40));
+    println!();
+
+    let volumes: Vec<u64> = days.iter().map(|(_, _, amount)| amount.0).collect();
+    println!("  Volume: {}", sparkline(&volumes).bright_yellow());
+    println!();
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Date").fg(Color::Cyan),
+        Cell::new("Requests").fg(Color::Cyan),
+        Cell::new("Volume").fg(Color::Cyan),
+    ]);
+
+    for (date, requests, amount) in &days {
+        table.add_row(vec![
+            Cell::new(date),
+            Cell::new(requests),
+            Cell::new(amount),
+        ]);
+    }
+
+    println!("{}", table);
+    println!();
+    println!(
+        "Total: {} requests, {}",
+        history.aggregated.requests, history.aggregated.amount
+    );
+    println!();
+}
+
+/// Render values as a row of block characters scaled to the maximum
+fn sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let max = values.iter().copied().max().unwrap_or_default();
+    values
+        .iter()
+        .map(|&value| {
+            if max == 0 {
+                BARS[0]
+            } else {
+                let level = (value as u128 * (BARS.len() as u128 - 1)).div_ceil(max as u128);
+                BARS[level as usize]
+            }
+        })
+        .collect()
+}
@@ -3,6 +3,7 @@ use crate::cli::ApiKeyCommands;
 use crate::client::ApiClient;
 use crate::config::manager::ConfigManager;
 use crate::error::Result;
+use crate::export::{export_records, ExportArgs};
 use colored::Colorize;
 use comfy_table::{Cell, Color, Table};
 use std::time::Duration;
@@ -10,7 +11,7 @@ use std::time::Duration;
 pub async fn handle_apikey_command(command: ApiKeyCommands, api_url: &str) -> Result<()> {
     match command {
         ApiKeyCommands::Create { name } => create_key(api_url, &name).await,
-        ApiKeyCommands::List => list_keys(api_url).await,
+        ApiKeyCommands::List { export } => list_keys(api_url, export).await,
         ApiKeyCommands::Revoke { key_id } => revoke_key(api_url, &key_id).await,
         ApiKeyCommands::Usage { key_id } => show_key_usage(api_url, &key_id).await,
     }
@@ -53,7 +54,7 @@ async fn create_key(api_url: &str, name: &str) -> Result<()> {
     Ok(())
 }
 
-async fn list_keys(api_url: &str) -> Result<()> {
+async fn list_keys(api_url: &str, export: ExportArgs) -> Result<()> {
     // Load credentials
     let credentials = CredentialsStore::load()?;
 
@@ -74,6 +75,10 @@ async fn list_keys(api_url: &str) -> Result<()> {
 
     spinner.finish_and_clear();
 
+    if let Some(format) = export.export {
+        return export_records(&keys, format, export.out.as_deref());
+    }
+
     if keys.is_empty() {
         println!("{}", "No API keys found.".yellow());
         println!();
@@ -92,11 +97,7 @@ async fn list_keys(api_url: &str) -> Result<()> {
 
     for key in &keys {
         // Format dates
-        let created_date = key
-            .created_at
-            .split('T')
-            .next()
-            .unwrap_or(&key.created_at);
+        let created_date = key.created_at.split('T').next().unwrap_or(&key.created_at);
 
         let last_used: String = if let Some(ref last_used_at) = key.last_used_at {
             last_used_at
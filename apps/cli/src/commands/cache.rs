@@ -0,0 +1,93 @@
+use crate::cli::CacheCommands;
+use crate::client::RpcCache;
+use crate::config::manager::ConfigManager;
+use crate::error::Result;
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use std::time::Duration;
+
+pub async fn handle_cache_command(command: CacheCommands) -> Result<()> {
+    let config = ConfigManager::new()?.load()?;
+    let cache = RpcCache::open(
+        Duration::from_secs(config.cache.ttl_seconds),
+        config.network.cluster,
+    )?;
+
+    match command {
+        CacheCommands::Stats => show_stats(&cache, config.cache.enabled),
+        CacheCommands::Clear => clear_cache(&cache),
+    }
+}
+
+fn show_stats(cache: &RpcCache, enabled: bool) -> Result<()> {
+    let stats = cache.stats()?;
+
+    let lookups = stats.hits + stats.misses;
+    let hit_rate = if lookups == 0 {
+        "-".to_string()
+    } else {
+        format!("{:.1}%", stats.hits as f64 / lookups as f64 * 100.0)
+    };
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Property").fg(Color::Cyan),
+        Cell::new("Value").fg(Color::Cyan),
+    ]);
+
+    table.add_row(vec![
+        Cell::new("Enabled"),
+        if enabled {
+            Cell::new("yes").fg(Color::Green)
+        } else {
+            Cell::new("no").fg(Color::Yellow)
+        },
+    ]);
+    table.add_row(vec![Cell::new("Entries"), Cell::new(stats.entries)]);
+    table.add_row(vec![Cell::new("Immutable"), Cell::new(stats.immutable)]);
+    table.add_row(vec![Cell::new("Expired"), Cell::new(stats.expired)]);
+    table.add_row(vec![
+        Cell::new("Size"),
+        Cell::new(format!("{:.1} KiB", stats.size_bytes as f64 / 1024.0)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Hits / Misses"),
+        Cell::new(format!("{} / {}", stats.hits, stats.misses)),
+    ]);
+    table.add_row(vec![Cell::new("Hit Rate"), Cell::new(hit_rate)]);
+
+    println!();
+    println!("{}", "RPC Cache".bold());
+    println!("{}", "=".repeat(40));
+    println!();
+    println!("{}", table);
+    println!();
+    println!(
+        "Cache directory: {}",
+        cache.dir().display().to_string().bright_black()
+    );
+
+    if !enabled {
+        println!();
+        println!(
+            "{}",
+            "Enable with: sdt config set cache.enabled true".bright_black()
+        );
+    }
+
+    println!();
+
+    Ok(())
+}
+
+fn clear_cache(cache: &RpcCache) -> Result<()> {
+    let removed = cache.clear()?;
+
+    println!();
+    println!("{}", "✓ Cache cleared".green().bold());
+    println!();
+    println!("  Removed {} cached responses", removed);
+    println!();
+
+    Ok(())
+}
@@ -1,46 +1,87 @@
-use crate::cli::DeployCommands;
+use crate::address::parse_address;
 use crate::auth::CredentialsStore;
+use crate::cli::DeployCommands;
+use crate::client::api::{Commitment, InitiateDeployRequest, InitiateDeployResponse};
+use crate::client::ApiClient;
+use crate::cluster::Cluster;
+use crate::commands::programs::format_timestamp;
+use crate::config::manager::ConfigManager;
+use crate::config::Config;
+use crate::deployer::{
+    self, AttestationAction, DeployAttestation, DeployerConfig, DeploymentStatus, HostedDeployment,
+    SignedAttestation,
+};
 use crate::error::{CliError, Result};
+use crate::fees::PriorityFeeArgs;
+use crate::keys;
+use crate::transaction::{sign_and_send, wait_for_confirmation};
+use base64::Engine;
+use chrono::Utc;
 use colored::Colorize;
+use futures_util::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
-use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
-
-#[derive(Debug, Serialize)]
-struct InitiateDeployRequest {
-    #[serde(rename = "projectName")]
-    project_name: String,
-    #[serde(rename = "programName")]
-    program_name: String,
-    description: Option<String>,
-    cluster: String,
-    #[serde(rename = "programPath")]
-    program_path: Option<String>,
-}
+use sha2::{Digest, Sha256};
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_loader_v3_interface::instruction as loader_instruction;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-#[derive(Debug, Deserialize)]
-struct InitiateDeployResponse {
-    project: ProjectResponse,
-    #[serde(rename = "programId")]
-    program_id: String,
-}
+/// Program bytes per loader `Write`, small enough to fit a single transaction
+const WRITE_CHUNK_SIZE: usize = 900;
+/// Program bytes per sponsored `write_buffer`, which needs more accounts than a loader `Write`
+const HOSTED_WRITE_CHUNK_SIZE: usize = 800;
+/// Buffer writes in flight at once
+const WRITE_CONCURRENCY: usize = 8;
+/// Times a buffer write is sent before giving up
+const WRITE_ATTEMPTS: usize = 3;
+/// How long to wait for a write before resending it with a fresh blockhash
+const WRITE_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(30);
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(60);
 
-#[derive(Debug, Deserialize)]
-struct ProjectResponse {
-    id: String,
-    name: String,
-    cluster: String,
+/// A program that is already hosted and can be upgraded in place
+struct HostedProgram {
+    client: ApiClient,
+    config: Config,
+    cluster: Cluster,
+    program: Pubkey,
+    deployment: HostedDeployment,
 }
 
-pub async fn handle_deploy_command(command: DeployCommands, api_url: &str) -> Result<()> {
+pub async fn handle_deploy_command(
+    command: DeployCommands,
+    api_url: &str,
+    cluster: Cluster,
+) -> Result<()> {
+    // Hosting goes through the API's RPC proxy, which only serves devnet
+    if !cluster.served_by_api() {
+        return Err(CliError::InvalidInput(format!(
+            "Hosted deployments are only available on devnet. Use 'solana program deploy' for {}",
+            cluster
+        )));
+    }
+
     match command {
         DeployCommands::Program {
             program_path,
             project,
             name,
-            cluster,
             description,
+            program_id,
+            keypair,
+            fee,
         } => {
+            // Programs that are already hosted are upgraded rather than redeployed
+            let hosted =
+                find_hosted_program(api_url, cluster, &program_path, program_id.as_deref()).await?;
+            if let Some(hosted) = hosted {
+                return upgrade_hosted_program(hosted, &program_path, keypair.as_deref(), &fee)
+                    .await;
+            }
+
             deploy_program(
                 api_url,
                 program_path,
@@ -48,23 +89,45 @@ pub async fn handle_deploy_command(command: DeployCommands, api_url: &str) -> Re
                 name,
                 cluster,
                 description,
+                program_id.as_deref(),
+                keypair.as_deref(),
+                &fee,
             )
             .await
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn deploy_program(
     api_url: &str,
     program_path: PathBuf,
     project_name: Option<String>,
     program_name: Option<String>,
-    cluster: String,
+    cluster: Cluster,
     description: Option<String>,
+    program_id: Option<&str>,
+    keypair: Option<&str>,
+    fee: &PriorityFeeArgs,
 ) -> Result<()> {
     // Get API token
     let credentials = CredentialsStore::load()?;
-    let api_token = &credentials.api_token;
+
+    // Load config for explorer links
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+    let client = ApiClient::new(api_url.to_string(), timeout)?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    // find_hosted_program already upgrades live programs, so an explicit
+    // address here is one that can't be deployed to
+    if let Some(program_id) = program_id {
+        return Err(CliError::InvalidInput(format!(
+            "{} is not an active hosted program. Omit --program-id to deploy to a new address",
+            program_id
+        )));
+    }
 
     println!(
         "{} {}",
@@ -85,7 +148,11 @@ async fn deploy_program(
     let project_name = project_name.unwrap_or_else(|| {
         std::env::current_dir()
             .ok()
-            .and_then(|p| p.file_name().and_then(|s| s.to_str()).map(|s| s.to_string()))
+            .and_then(|p| {
+                p.file_name()
+                    .and_then(|s| s.to_str())
+                    .map(|s| s.to_string())
+            })
             .unwrap_or_else(|| "default".to_string())
     });
 
@@ -96,10 +163,73 @@ async fn deploy_program(
             program_path.display()
         )));
     }
+    let bytes = read_program(&program_path)?;
+
+    let payer = match keypair {
+        Some(reference) => keys::read_keypair(&keys::resolve_keypair_path(reference)?)?,
+        None => keys::default_signer(&config)?,
+    };
+    let program_keypair = match program_keypair_path(&program_path) {
+        Some(path) => keys::read_keypair(&path)?,
+        None => new_program_keypair(&program_path)?,
+    };
+    let program = program_keypair.pubkey();
+
+    let deployer_config = deployer::fetch_config(&client).await?.ok_or_else(|| {
+        CliError::Other(format!("Program hosting is not available on {}", cluster))
+    })?;
+    if deployer_config.paused {
+        return Err(CliError::Other(
+            "Program hosting is paused. Try again later".to_string(),
+        ));
+    }
+    if bytes.len() > deployer_config.max_program_size as usize {
+        return Err(CliError::InvalidInput(format!(
+            "{} is {} bytes, but hosted programs can be at most {} bytes",
+            program_path.display(),
+            bytes.len(),
+            deployer_config.max_program_size
+        )));
+    }
+    let program_hash: [u8; 32] = Sha256::digest(&bytes).into();
+
+    // A registration left behind by an interrupted deploy is picked up where it stopped
+    let registered = match deployer::fetch_deployment(&client, &program).await? {
+        None => None,
+        Some(deployment)
+            if deployment.status == DeploymentStatus::Registered
+                && deployment.deployer == payer.pubkey()
+                && !deployment_expired(&deployment) =>
+        {
+            if deployment.program_hash != program_hash {
+                return Err(CliError::InvalidInput(format!(
+                    "{} is registered for a different build. Deploy it unchanged, or \
+                     wait until {} for the registration to expire",
+                    program,
+                    format_timestamp(deployment.expires_at)
+                )));
+            }
+            Some(deployment)
+        }
+        Some(_) => {
+            return Err(CliError::InvalidInput(format!(
+                "{} has already been used with the deployer. Remove {} to deploy to a new address",
+                program,
+                program_keypair_path(&program_path)
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_else(|| "the program keypair".to_string())
+            )))
+        }
+    };
 
     println!("  {} {}", "Project:".dimmed(), project_name.cyan());
     println!("  {} {}", "Program:".dimmed(), program_name.cyan());
-    println!("  {} {}", "Cluster:".dimmed(), cluster.cyan());
+    println!(
+        "  {} {}",
+        "Program ID:".dimmed(),
+        program.to_string().cyan()
+    );
+    println!("  {} {}", "Cluster:".dimmed(), cluster.to_string().cyan());
     println!(
         "  {} {}",
         "File:".dimmed(),
@@ -107,75 +237,660 @@ async fn deploy_program(
     );
     println!();
 
-    // Step 1: Call API to initiate deployment (creates project/program records)
+    // Price every transaction of the deploy off the accounts it writes
+    let compute_budget = fee
+        .instructions(
+            &client,
+            &[
+                program,
+                deployer::deployment_address(&program),
+                deployer::treasury_address(),
+            ],
+        )
+        .await?;
+
+    // Step 1: Register the deployment and have the treasury fund its buffer, in
+    // one transaction so a registration always has a buffer to write
+    let buffer = match registered {
+        Some(deployment) => {
+            println!("{} Resuming registered deployment", "↻".cyan().bold());
+            deployment.buffer
+        }
+        None => {
+            let attestation = request_attestation(
+                &client,
+                &deployer_config,
+                &payer,
+                AttestationAction::Register,
+                &program,
+                &program_hash,
+                &bytes,
+            )
+            .await?;
+            let buffer = Keypair::new();
+            let instructions = [
+                attestation.verify_instruction(),
+                deployer::register_deployment(
+                    &program,
+                    &buffer.pubkey(),
+                    &payer.pubkey(),
+                    &attestation.attestation,
+                ),
+                deployer::fund_buffer(&program, &buffer.pubkey(), &payer.pubkey()),
+            ];
+            let signature =
+                sign_and_send(&client, &compute_budget, &instructions, &[&payer, &buffer]).await?;
+            wait_for_confirmation(
+                &client,
+                &signature,
+                Commitment::Confirmed,
+                CONFIRMATION_TIMEOUT,
+            )
+            .await?;
+            println!("{} Deployment registered", "✓".green().bold());
+            buffer.pubkey()
+        }
+    };
+
+    // Step 2: Write the program into the sponsored buffer and deploy it
+    let writes: Vec<Instruction> = bytes
+        .chunks(HOSTED_WRITE_CHUNK_SIZE)
+        .enumerate()
+        .map(|(index, chunk)| {
+            deployer::write_buffer(
+                &program,
+                &buffer,
+                &payer.pubkey(),
+                (index * HOSTED_WRITE_CHUNK_SIZE) as u32,
+                chunk,
+            )
+        })
+        .collect();
+    let result = async {
+        send_writes(&client, &compute_budget, &payer, &writes).await?;
+        let deploy = deployer::deploy_program(&program, &buffer, &payer.pubkey());
+        let signature = sign_and_send(
+            &client,
+            &compute_budget,
+            &[deploy],
+            &[&payer, &program_keypair],
+        )
+        .await?;
+        wait_for_confirmation(
+            &client,
+            &signature,
+            Commitment::Confirmed,
+            CONFIRMATION_TIMEOUT,
+        )
+        .await?;
+        Ok(signature)
+    }
+    .await;
+    let signature = match result {
+        Ok(signature) => signature,
+        Err(e) => {
+            // The treasury paid for the buffer, so there is nothing to recover,
+            // only a registration to finish before it expires
+            eprintln!(
+                "{} {} is registered but not deployed. Run the same command again to resume",
+                "⚠".yellow(),
+                program
+            );
+            return Err(e);
+        }
+    };
+    println!("{} Program deployed!", "✓".green().bold());
+
+    // Step 3: Record the program with the API (creates project/program records)
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::default_spinner()
             .template("{spinner:.green} {msg}")
             .unwrap(),
     );
-    spinner.set_message("Registering deployment with API...");
+    spinner.set_message("Recording deployment with API...");
     spinner.enable_steady_tick(std::time::Duration::from_millis(100));
 
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!("{}/projects/deploy", api_url))
-        .bearer_auth(&api_token)
-        .json(&InitiateDeployRequest {
+    let recorded = record_deployment(
+        &client,
+        &InitiateDeployRequest {
             project_name: project_name.clone(),
             program_name: program_name.clone(),
             description: description.clone(),
-            cluster: cluster.clone(),
+            cluster,
             program_path: Some(program_path.display().to_string()),
-        })
-        .send()
+            program_address: Some(program.to_string()),
+        },
+    )
+    .await;
+    spinner.finish_and_clear();
+
+    println!();
+    match recorded {
+        Ok(deploy_response) => println!(
+            "  {} {}",
+            "Project ID:".dimmed(),
+            deploy_response.project.id.cyan()
+        ),
+        // The program is live either way; only the dashboard misses it
+        Err(e) => eprintln!(
+            "{} Failed to record the deployment with the API: {}",
+            "⚠".yellow(),
+            e
+        ),
+    }
+    println!(
+        "  {} {}",
+        "Program ID:".dimmed(),
+        program.to_string().cyan()
+    );
+    println!("  {} {}", "Signature:".dimmed(), signature);
+    println!(
+        "  {} {}",
+        "Explorer:".dimmed(),
+        config
+            .network
+            .explorer
+            .address_url(&program.to_string(), cluster)
+            .bright_black()
+    );
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Hosting is free until it expires. Keep it longer with 'sdt programs extend {}'.",
+            program
+        )
+        .dimmed()
+    );
+
+    Ok(())
+}
+
+/// Create the project and program records for a deployed program and mark it deployed
+async fn record_deployment(
+    client: &ApiClient,
+    request: &InitiateDeployRequest,
+) -> Result<InitiateDeployResponse> {
+    let deploy_response = client.initiate_deployment(request).await?;
+
+    client
+        .update_program_status(&deploy_response.program_id, "deployed")
         .await?;
 
-    if !response.status().is_success() {
-        spinner.finish_and_clear();
-        let error_text = response.text().await.unwrap_or_default();
+    Ok(deploy_response)
+}
+
+/// Have the API attest `payer` registering or upgrading `program` to `bytes`,
+/// checking the signer is the one the deployer accepts
+async fn request_attestation(
+    client: &ApiClient,
+    deployer_config: &DeployerConfig,
+    payer: &Keypair,
+    action: AttestationAction,
+    program: &Pubkey,
+    program_hash: &[u8; 32],
+    bytes: &[u8],
+) -> Result<SignedAttestation> {
+    let program_size = bytes.len() as u32;
+    let response = client
+        .request_deploy_attestation(
+            &payer.pubkey().to_string(),
+            action,
+            &program.to_string(),
+            program_hash,
+            program_size,
+        )
+        .await?;
+
+    let signer = parse_address(&response.attestation_key)?;
+    if signer != deployer_config.attestation_key {
         return Err(CliError::ApiError(format!(
-            "Failed to initiate deployment: {}",
-            error_text
+            "The API attests with {}, but the deployer only accepts {}",
+            signer, deployer_config.attestation_key
         )));
     }
+    let nonce = response.nonce.parse().map_err(|_| {
+        CliError::ApiError(format!("Invalid attestation nonce: {}", response.nonce))
+    })?;
+    let signature = base64::engine::general_purpose::STANDARD
+        .decode(&response.signature)
+        .ok()
+        .and_then(|signature| <[u8; 64]>::try_from(signature).ok())
+        .ok_or_else(|| CliError::ApiError("Invalid attestation signature".to_string()))?;
 
-    let deploy_response: InitiateDeployResponse = response
-        .json()
-        .await
-        .map_err(|e| CliError::ApiError(format!("Failed to parse response: {}", e)))?;
+    Ok(SignedAttestation {
+        attestation: DeployAttestation {
+            user: payer.pubkey(),
+            action,
+            program: *program,
+            program_hash: *program_hash,
+            program_size,
+            expiry: response.expiry,
+            nonce,
+        },
+        signer,
+        signature,
+    })
+}
 
-    spinner.finish_with_message("✓ Deployment registered".green().to_string());
+fn read_program(program_path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(program_path).map_err(|e| {
+        CliError::InvalidInput(format!(
+            "Failed to read program file {}: {}",
+            program_path.display(),
+            e
+        ))
+    })
+}
 
-    println!();
+/// The keypair `cargo build-sbf` writes next to the .so file, if there is one
+fn program_keypair_path(program_path: &Path) -> Option<PathBuf> {
+    let keypair_path = keypair_path_for(program_path)?;
+    keypair_path.is_file().then_some(keypair_path)
+}
+
+/// Generate a program address and save it where `cargo build-sbf` would, so
+/// the deploy can be resumed and the program upgraded later
+fn new_program_keypair(program_path: &Path) -> Result<Keypair> {
+    let keypair = Keypair::new();
+    if let Some(keypair_path) = keypair_path_for(program_path) {
+        keys::write_keypair(&keypair_path, &keypair)?;
+        println!(
+            "  {} {}",
+            "Saved program keypair:".dimmed(),
+            keypair_path.display().to_string().cyan()
+        );
+    }
+    Ok(keypair)
+}
+
+fn keypair_path_for(program_path: &Path) -> Option<PathBuf> {
+    let stem = program_path.file_stem().and_then(|s| s.to_str())?;
+    Some(program_path.with_file_name(format!("{}-keypair.json", stem)))
+}
+
+/// Look up the hosted deployment for `--program-id`, or for the program
+/// keypair `cargo build-sbf` writes next to the .so file
+async fn find_hosted_program(
+    api_url: &str,
+    cluster: Cluster,
+    program_path: &Path,
+    program_id: Option<&str>,
+) -> Result<Option<HostedProgram>> {
+    let program = match program_id {
+        Some(program_id) => parse_address(program_id)?,
+        None => {
+            let Some(keypair_path) = program_keypair_path(program_path) else {
+                return Ok(None);
+            };
+            keys::read_keypair(&keypair_path)?.pubkey()
+        }
+    };
+
+    let credentials = CredentialsStore::load()?;
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+    let client = ApiClient::new(api_url.to_string(), timeout)?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    let Some(deployment) = deployer::fetch_deployment(&client, &program).await? else {
+        return Ok(None);
+    };
+
+    match deployment.status {
+        DeploymentStatus::Hosted if !deployment_expired(&deployment) => Ok(Some(HostedProgram {
+            client,
+            config,
+            cluster,
+            program,
+            deployment,
+        })),
+        DeploymentStatus::Claimed => Err(CliError::InvalidInput(format!(
+            "{} has been claimed and is no longer hosted. Upgrade it with \
+             'solana program deploy --program-id {}'",
+            program, program
+        ))),
+        // Registered programs are finished by the deploy path, and expired
+        // ones can't be upgraded
+        _ => Ok(None),
+    }
+}
+
+fn deployment_expired(deployment: &HostedDeployment) -> bool {
+    Utc::now().timestamp() >= deployment.expires_at
+}
+
+async fn upgrade_hosted_program(
+    hosted: HostedProgram,
+    program_path: &Path,
+    keypair: Option<&str>,
+    fee: &PriorityFeeArgs,
+) -> Result<()> {
+    let HostedProgram {
+        client,
+        config,
+        cluster,
+        program,
+        deployment,
+    } = hosted;
+
+    let payer = match keypair {
+        Some(reference) => keys::read_keypair(&keys::resolve_keypair_path(reference)?)?,
+        None => keys::default_signer(&config)?,
+    };
+    if payer.pubkey() != deployment.deployer {
+        return Err(CliError::InvalidInput(format!(
+            "{} was deployed by {}. Pass --keypair with that wallet to upgrade it",
+            program, deployment.deployer
+        )));
+    }
+
+    let bytes = read_program(program_path)?;
+
+    // The programdata account was sized for the first deployment
+    if bytes.len() > deployment.program_size as usize {
+        return Err(CliError::InvalidInput(format!(
+            "{} is {} bytes, but hosted program {} only has room for {} bytes. \
+             Upgrades cannot grow a hosted program; claim it and use 'solana program extend'",
+            program_path.display(),
+            bytes.len(),
+            program,
+            deployment.program_size
+        )));
+    }
+
+    let new_hash: [u8; 32] = Sha256::digest(&bytes).into();
+    if new_hash == deployment.program_hash {
+        println!(
+            "{} {} is already running this build",
+            "✓".green().bold(),
+            program
+        );
+        return Ok(());
+    }
+
+    println!("{} {}", "🔁".bold(), "Upgrading hosted program...".bold());
     println!(
         "  {} {}",
-        "Project ID:".dimmed(),
-        deploy_response.project.id.cyan()
+        "Program ID:".dimmed(),
+        program.to_string().cyan()
     );
+    println!("  {} {}", "Cluster:".dimmed(), cluster.to_string().cyan());
     println!(
         "  {} {}",
-        "Program ID:".dimmed(),
-        deploy_response.program_id.cyan()
+        "File:".dimmed(),
+        program_path.display().to_string().cyan()
     );
-    println!();
-
-    // Step 2: TODO - Actual deployment will happen here
-    // This will be implemented when the Solana program deployment logic is ready
     println!(
-        "{}",
-        "⏳ Actual deployment logic will be implemented when the Solana program is ready"
-            .yellow()
+        "  {} {}",
+        "Upgrades so far:".dimmed(),
+        deployment.upgrade_count.to_string().cyan()
     );
     println!();
+
+    // Price every transaction of the upgrade off the accounts the upgrade writes
+    let compute_budget = fee
+        .instructions(&client, &[program, deployer::deployment_address(&program)])
+        .await?;
+
+    // Only bytes the API attested can be swapped in, so ask before paying for a buffer
+    let deployer_config = deployer::fetch_config(&client).await?.ok_or_else(|| {
+        CliError::Other(format!("Program hosting is not available on {}", cluster))
+    })?;
+    if deployer_config.paused {
+        return Err(CliError::Other(
+            "Program hosting is paused. Try again later".to_string(),
+        ));
+    }
+    let attestation = request_attestation(
+        &client,
+        &deployer_config,
+        &payer,
+        AttestationAction::Upgrade,
+        &program,
+        &new_hash,
+        &bytes,
+    )
+    .await?;
+
+    let buffer = Keypair::new();
+    let lamports = client
+        .get_minimum_balance_for_rent_exemption(UpgradeableLoaderState::size_of_buffer(bytes.len()))
+        .await?;
+    let create = loader_instruction::create_buffer(
+        &payer.pubkey(),
+        &buffer.pubkey(),
+        &payer.pubkey(),
+        lamports,
+        bytes.len(),
+    )
+    .map_err(|e| CliError::Other(format!("Failed to build buffer instructions: {}", e)))?;
+
+    let signature = sign_and_send(&client, &compute_budget, &create, &[&payer, &buffer]).await?;
+    wait_for_confirmation(
+        &client,
+        &signature,
+        Commitment::Confirmed,
+        CONFIRMATION_TIMEOUT,
+    )
+    .await?;
+
+    // Until the upgrade lands the buffer is ours, so close it to get the SOL back
+    let result = write_and_upgrade(
+        &client,
+        &compute_budget,
+        &payer,
+        &buffer.pubkey(),
+        &program,
+        &bytes,
+        &attestation,
+    )
+    .await;
+    let signature = match result {
+        Ok(signature) => signature,
+        Err(e) => {
+            close_buffer(&client, &compute_budget, &payer, &buffer.pubkey()).await;
+            return Err(e);
+        }
+    };
+
+    println!("{} Program upgraded!", "✓".green().bold());
     println!(
-        "{}",
-        "For now, the program record has been created in the database.".dimmed()
+        "  {} {}",
+        "Upgrade:".dimmed(),
+        format!("#{}", deployment.upgrade_count + 1).cyan()
     );
+    println!("  {} {}", "Signature:".dimmed(), signature);
     println!(
-        "{}",
-        "The CLI will update the program address after deployment.".dimmed()
+        "  {} {}",
+        "Explorer:".dimmed(),
+        config
+            .network
+            .explorer
+            .address_url(&program.to_string(), cluster)
+            .bright_black()
+    );
+
+    Ok(())
+}
+
+/// Write `bytes` into `buffer`, then hand it to the deployer and upgrade
+/// `program` from it in one transaction
+async fn write_and_upgrade(
+    client: &ApiClient,
+    compute_budget: &[Instruction],
+    payer: &Keypair,
+    buffer: &Pubkey,
+    program: &Pubkey,
+    bytes: &[u8],
+    attestation: &SignedAttestation,
+) -> Result<String> {
+    let writes: Vec<Instruction> = bytes
+        .chunks(WRITE_CHUNK_SIZE)
+        .enumerate()
+        .map(|(index, chunk)| {
+            loader_instruction::write(
+                buffer,
+                &payer.pubkey(),
+                (index * WRITE_CHUNK_SIZE) as u32,
+                chunk.to_vec(),
+            )
+        })
+        .collect();
+    send_writes(client, compute_budget, payer, &writes).await?;
+
+    let instructions = [
+        loader_instruction::set_buffer_authority(
+            buffer,
+            &payer.pubkey(),
+            &deployer::authority_address(),
+        ),
+        attestation.verify_instruction(),
+        deployer::upgrade_hosted(program, buffer, &payer.pubkey(), &attestation.attestation),
+    ];
+    let signature = sign_and_send(client, compute_budget, &instructions, &[payer]).await?;
+    wait_for_confirmation(
+        client,
+        &signature,
+        Commitment::Confirmed,
+        CONFIRMATION_TIMEOUT,
+    )
+    .await?;
+
+    Ok(signature)
+}
+
+/// Close a buffer the upgrade never consumed, refunding its SOL to `payer`, or
+/// say how to if that fails too
+async fn close_buffer(
+    client: &ApiClient,
+    compute_budget: &[Instruction],
+    payer: &Keypair,
+    buffer: &Pubkey,
+) {
+    let close = loader_instruction::close(buffer, &payer.pubkey(), &payer.pubkey());
+    let closed = match sign_and_send(client, compute_budget, &[close], &[payer]).await {
+        Ok(signature) => {
+            wait_for_confirmation(
+                client,
+                &signature,
+                Commitment::Confirmed,
+                CONFIRMATION_TIMEOUT,
+            )
+            .await
+        }
+        Err(e) => Err(e),
+    };
+
+    match closed {
+        Ok(_) => eprintln!(
+            "{} Closed buffer {} and returned its SOL",
+            "⚠".yellow(),
+            buffer
+        ),
+        Err(_) => eprintln!(
+            "{} Buffer {} still holds your SOL. Recover it with 'solana program close {}'",
+            "⚠".yellow(),
+            buffer,
+            buffer
+        ),
+    }
+}
+
+/// Send buffer writes concurrently, resending each one that fails or isn't
+/// confirmed in time
+async fn send_writes(
+    client: &ApiClient,
+    compute_budget: &[Instruction],
+    payer: &Keypair,
+    writes: &[Instruction],
+) -> Result<()> {
+    let progress = ProgressBar::new(writes.len() as u64);
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template("{bar:40.cyan/blue} {pos}/{len} {msg}")
+            .unwrap(),
     );
+    progress.set_message("Writing buffer...");
+
+    let mut results = futures_util::stream::iter(
+        writes
+            .iter()
+            .map(|write| send_write(client, compute_budget, payer, write)),
+    )
+    .buffer_unordered(WRITE_CONCURRENCY);
+
+    while let Some(result) = results.next().await {
+        if let Err(e) = result {
+            progress.finish_and_clear();
+            return Err(e);
+        }
+        progress.inc(1);
+    }
+    progress.finish_and_clear();
 
     Ok(())
 }
+
+/// Writes are idempotent, so a resend that races a late confirmation is harmless
+async fn send_write(
+    client: &ApiClient,
+    compute_budget: &[Instruction],
+    payer: &Keypair,
+    write: &Instruction,
+) -> Result<()> {
+    let mut attempt = 1;
+    loop {
+        let result = match sign_and_send(
+            client,
+            compute_budget,
+            std::slice::from_ref(write),
+            &[payer],
+        )
+        .await
+        {
+            Ok(signature) => confirm_write(client, &signature).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Err(e) if attempt < WRITE_ATTEMPTS => {
+                tracing::debug!("Buffer write attempt {} failed: {}", attempt, e);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Like [`wait_for_confirmation`], but without a spinner of its own so many
+/// writes can be confirmed under one progress bar
+async fn confirm_write(client: &ApiClient, signature: &str) -> Result<()> {
+    let poll_interval = Duration::from_secs(1);
+    let deadline = tokio::time::Instant::now() + WRITE_CONFIRMATION_TIMEOUT;
+
+    loop {
+        if let Some(status) = client.get_signature_status(signature).await? {
+            if let Some(err) = status.err {
+                return Err(CliError::TransactionFailed {
+                    signature: signature.to_string(),
+                    message: err.to_string(),
+                });
+            }
+            if status
+                .confirmation_status
+                .is_some_and(|reached| reached >= Commitment::Confirmed)
+            {
+                return Ok(());
+            }
+        }
+
+        if tokio::time::Instant::now() + poll_interval > deadline {
+            return Err(CliError::ConfirmationTimeout(signature.to_string()));
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
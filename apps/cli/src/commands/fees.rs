@@ -0,0 +1,104 @@
+use crate::address::parse_address;
+use crate::auth::CredentialsStore;
+use crate::client::ApiClient;
+use crate::cluster::Cluster;
+use crate::config::manager::ConfigManager;
+use crate::error::Result;
+use crate::fees::{estimate_priority_fees, REPORTED_PERCENTILES};
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use std::time::Duration;
+
+pub async fn handle_fees_command(
+    accounts: Vec<String>,
+    percentile: u8,
+    api_url: &str,
+    cluster: Cluster,
+) -> Result<()> {
+    // Validate addresses before contacting the API
+    let accounts = accounts
+        .iter()
+        .map(|address| parse_address(address))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+
+    // Load config for timeout
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+
+    // Create API client
+    let client = ApiClient::new(api_url.to_string(), timeout)?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    // Show spinner
+    let spinner = indicatif::ProgressBar::new_spinner();
+    spinner.set_message("Fetching recent prioritization fees...");
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    let estimate = estimate_priority_fees(&client, &accounts).await?;
+
+    spinner.finish_and_clear();
+
+    if estimate.samples() == 0 {
+        println!("{}", "No recent prioritization fees returned.".yellow());
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Percentile").fg(Color::Cyan),
+        Cell::new("Micro-lamports / CU").fg(Color::Cyan),
+    ]);
+
+    table.add_row(vec![Cell::new("min"), Cell::new(estimate.min())]);
+    for p in REPORTED_PERCENTILES {
+        let row = vec![
+            Cell::new(format!("p{}", p)),
+            Cell::new(estimate.percentile(p)),
+        ];
+        if p == percentile {
+            table.add_row(
+                row.into_iter()
+                    .map(|c| c.fg(Color::Green))
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            table.add_row(row);
+        }
+    }
+    table.add_row(vec![Cell::new("max"), Cell::new(estimate.max())]);
+    table.add_row(vec![
+        Cell::new("mean"),
+        Cell::new(format!("{:.0}", estimate.mean())),
+    ]);
+
+    println!();
+    println!("{}", "Priority Fees".bold());
+    println!("{}", "=".repeat(40));
+    println!();
+    println!(
+        "  Sampled {} slots on {} ({} - {})",
+        estimate.samples(),
+        cluster,
+        estimate.first_slot,
+        estimate.last_slot
+    );
+    if !accounts.is_empty() {
+        let accounts: Vec<String> = accounts.iter().map(|a| a.to_string()).collect();
+        println!("  Accounts: {}", accounts.join(", ").bright_cyan());
+    }
+    println!();
+    println!("{}", table);
+    println!();
+    println!(
+        "  Suggested compute unit price (p{}): {} micro-lamports",
+        percentile,
+        estimate.percentile(percentile).to_string().bright_yellow()
+    );
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use crate::address::{default_keypair_path, read_keypair_pubkey};
+use crate::cli::{DerivationArgs, KeysCommands};
+use crate::config::manager::ConfigManager;
+use crate::error::{CliError, Result};
+use crate::keys::{keypair_from_bip39_seed, read_keypair, KeyStore, StoredKey};
+use bip39::Mnemonic;
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use std::io::IsTerminal;
+use std::path::Path;
+
+pub async fn handle_keys_command(command: KeysCommands) -> Result<()> {
+    match command {
+        KeysCommands::New {
+            name,
+            word_count,
+            derivation,
+            default,
+            force,
+        } => new_key(&name, &word_count, &derivation, default, force),
+        KeysCommands::Import {
+            name,
+            file,
+            seed_phrase: _,
+            derivation,
+            default,
+            force,
+        } => import_key(&name, file.as_deref(), &derivation, default, force),
+        KeysCommands::List => list_keys(),
+        KeysCommands::Show { name } => show_key(name.as_deref()),
+        KeysCommands::ExportPubkey { name } => export_pubkey(name.as_deref()),
+    }
+}
+
+fn new_key(
+    name: &str,
+    word_count: &str,
+    derivation: &DerivationArgs,
+    default: bool,
+    force: bool,
+) -> Result<()> {
+    let word_count: usize = word_count
+        .parse()
+        .map_err(|_| CliError::InvalidInput(format!("Invalid word count: {}", word_count)))?;
+    let mnemonic = Mnemonic::generate(word_count)
+        .map_err(|e| CliError::Other(format!("Failed to generate seed phrase: {}", e)))?;
+
+    let passphrase = prompt_passphrase(derivation.passphrase, true)?;
+    let keypair = keypair_from_bip39_seed(
+        &mnemonic.to_seed(passphrase.as_str()),
+        derivation.derivation_path.as_deref(),
+    )?;
+
+    let key = KeyStore::open()?.save(name, &keypair, force)?;
+    let is_default = update_default_signer(&key, default)?;
+
+    println!();
+    println!("{}", format!("✓ Created key '{}'", key.name).green().bold());
+    print_key_details(&key, derivation, is_default);
+
+    println!(
+        "  {}",
+        "⚠ Save this seed phrase to recover the key - it won't be shown again!".yellow()
+    );
+    println!();
+    println!("  {}", mnemonic.to_string().bright_yellow());
+    println!();
+
+    Ok(())
+}
+
+fn import_key(
+    name: &str,
+    file: Option<&Path>,
+    derivation: &DerivationArgs,
+    default: bool,
+    force: bool,
+) -> Result<()> {
+    let keypair = match file {
+        Some(path) => {
+            if derivation.derivation_path.is_some() || derivation.passphrase {
+                return Err(CliError::InvalidInput(
+                    "--derivation-path and --passphrase only apply to --seed-phrase".to_string(),
+                ));
+            }
+            read_keypair(path)?
+        }
+        None => {
+            let phrase = read_secret("Seed phrase: ")?;
+            let mnemonic = Mnemonic::parse(phrase.trim())
+                .map_err(|e| CliError::InvalidInput(format!("Invalid seed phrase: {}", e)))?;
+            let passphrase = prompt_passphrase(derivation.passphrase, false)?;
+
+            keypair_from_bip39_seed(
+                &mnemonic.to_seed(passphrase.as_str()),
+                derivation.derivation_path.as_deref(),
+            )?
+        }
+    };
+
+    let key = KeyStore::open()?.save(name, &keypair, force)?;
+    let is_default = update_default_signer(&key, default)?;
+
+    println!();
+    println!(
+        "{}",
+        format!("✓ Imported key '{}'", key.name).green().bold()
+    );
+    print_key_details(&key, derivation, is_default);
+
+    Ok(())
+}
+
+fn list_keys() -> Result<()> {
+    let store = KeyStore::open()?;
+    let keys = store.list()?;
+
+    if keys.is_empty() {
+        println!("{}", "No keys found.".yellow());
+        println!();
+        println!("Create one with: sdt keys new <name>");
+        return Ok(());
+    }
+
+    let config = ConfigManager::new()?.load()?;
+    let default_path = default_keypair_path(&config).ok();
+
+    // Create table
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Name").fg(Color::Cyan),
+        Cell::new("Address").fg(Color::Cyan),
+        Cell::new("Default").fg(Color::Cyan),
+    ]);
+
+    for key in &keys {
+        let is_default = default_path.as_deref() == Some(key.path.as_path());
+        table.add_row(vec![
+            Cell::new(&key.name),
+            Cell::new(key.pubkey),
+            if is_default {
+                Cell::new("✓").fg(Color::Green)
+            } else {
+                Cell::new("")
+            },
+        ]);
+    }
+
+    println!();
+    println!("{}", table);
+    println!();
+    println!(
+        "  Stored in {}",
+        store.dir().display().to_string().bright_black()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn show_key(name: Option<&str>) -> Result<()> {
+    let config = ConfigManager::new()?.load()?;
+    let default_path = default_keypair_path(&config)?;
+
+    let (label, path, pubkey) = match name {
+        Some(name) => {
+            let key = KeyStore::open()?.get(name)?;
+            (key.name, key.path, key.pubkey)
+        }
+        None => {
+            let pubkey = read_default_pubkey(&default_path)?;
+            ("default signer".to_string(), default_path.clone(), pubkey)
+        }
+    };
+
+    println!();
+    println!("{}", format!("Key: {}", label).bold());
+    println!("{}", "=".repeat(40));
+    println!();
+    println!("  Address: {}", pubkey.to_string().bright_cyan());
+    println!("  File:    {}", path.display());
+    println!(
+        "  Default: {}",
+        if path == default_path { "yes" } else { "no" }
+    );
+    println!();
+
+    Ok(())
+}
+
+fn export_pubkey(name: Option<&str>) -> Result<()> {
+    let pubkey = match name {
+        Some(name) => KeyStore::open()?.get(name)?.pubkey,
+        None => {
+            let config = ConfigManager::new()?.load()?;
+            read_default_pubkey(&default_keypair_path(&config)?)?
+        }
+    };
+
+    println!("{}", pubkey);
+
+    Ok(())
+}
+
+fn read_default_pubkey(path: &Path) -> Result<solana_pubkey::Pubkey> {
+    if !path.is_file() {
+        return Err(CliError::InvalidInput(format!(
+            "No default signer found at {}. Create one with 'sdt keys new <name> --default'",
+            path.display()
+        )));
+    }
+    read_keypair_pubkey(path)
+}
+
+/// Point wallet.keypair at a new key when asked to, or when there is no signer yet
+fn update_default_signer(key: &StoredKey, requested: bool) -> Result<bool> {
+    let manager = ConfigManager::new()?;
+    let mut config = manager.load()?;
+
+    let current = default_keypair_path(&config)?;
+    if !requested && current.is_file() {
+        return Ok(current == key.path);
+    }
+
+    config.wallet.keypair = Some(key.name.clone());
+    manager.save(&config)?;
+    Ok(true)
+}
+
+fn prompt_passphrase(enabled: bool, confirm: bool) -> Result<String> {
+    if !enabled {
+        return Ok(String::new());
+    }
+
+    let passphrase = read_secret("BIP39 passphrase: ")?;
+    if confirm && read_secret("Confirm passphrase: ")? != passphrase {
+        return Err(CliError::InvalidInput(
+            "Passphrases do not match".to_string(),
+        ));
+    }
+
+    Ok(passphrase)
+}
+
+/// Read a secret without echo, or a line from stdin when it is piped
+fn read_secret(prompt: &str) -> Result<String> {
+    let result = if std::io::stdin().is_terminal() {
+        rpassword::prompt_password(prompt)
+    } else {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line).map(|_| line)
+    };
+
+    result
+        .map(|secret| secret.trim_end_matches(['\r', '\n']).to_string())
+        .map_err(|e| CliError::Other(format!("Failed to read input: {}", e)))
+}
+
+fn print_key_details(key: &StoredKey, derivation: &DerivationArgs, is_default: bool) {
+    println!();
+    println!("  Address: {}", key.pubkey.to_string().bright_cyan());
+    println!("  File:    {}", key.path.display());
+    if let Some(ref path) = derivation.derivation_path {
+        println!("  Path:    {}", path);
+    }
+    if is_default {
+        println!("  {}", "Default signer (wallet.keypair)".green());
+    }
+    println!();
+}
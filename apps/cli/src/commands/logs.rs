@@ -0,0 +1,197 @@
+use crate::auth::CredentialsStore;
+use crate::cli::LogsArgs;
+use crate::client::{ApiClient, PubsubClient};
+use crate::cluster::Cluster;
+use crate::config::manager::ConfigManager;
+use crate::error::{CliError, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use std::time::Duration;
+
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+const PROGRAM_LOG_PREFIX: &str = "Program log: ";
+
+#[derive(Debug, Deserialize)]
+struct LogsNotification {
+    context: NotificationContext,
+    value: LogsValue,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationContext {
+    slot: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct LogsValue {
+    signature: String,
+    err: Option<serde_json::Value>,
+    logs: Vec<String>,
+}
+
+pub async fn handle_logs_command(args: LogsArgs, api_url: &str, cluster: Cluster) -> Result<()> {
+    let config = ConfigManager::new()?.load()?;
+
+    // Resolve websocket URL (CLI flag > config file); the API has no pubsub proxy
+    let url = args.ws_url.or(config.rpc.ws_url).ok_or_else(|| {
+        CliError::InvalidInput(
+            "No websocket URL configured. Pass --ws-url or run \
+             'sdt config set rpc.ws_url <url>'"
+                .to_string(),
+        )
+    })?;
+    let client = PubsubClient::new(url);
+
+    // Transactions are only looked up once confirmed
+    let filter = if args.mentions.is_empty() {
+        None
+    } else {
+        if args.commitment == "processed" {
+            return Err(CliError::InvalidInput(
+                "--mentions needs --commitment confirmed or finalized".to_string(),
+            ));
+        }
+
+        let timeout = Duration::from_secs(config.api.timeout_seconds);
+        let lookup = ApiClient::new(api_url.to_string(), timeout)?
+            .with_credentials(CredentialsStore::load()?)
+            .with_cluster(cluster);
+        Some(MentionsFilter {
+            addresses: args.mentions,
+            lookup,
+        })
+    };
+
+    println!(
+        "{} {}",
+        "Streaming logs from".bold(),
+        client.url().bright_blue()
+    );
+    println!("  {} {}", "Program:".dimmed(), args.program_id.cyan());
+    if let Some(ref filter) = filter {
+        println!(
+            "  {} {}",
+            "Mentions:".dimmed(),
+            filter.addresses.join(", ").cyan()
+        );
+    }
+    println!("  {} {}", "Commitment:".dimmed(), args.commitment.cyan());
+    println!("{}", "Press Ctrl+C to stop.".bright_black());
+    println!();
+
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        let outcome = tokio::select! {
+            result = stream_logs(&client, &args.program_id, filter.as_ref(), &args.commitment, &mut backoff) => result,
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        };
+
+        match outcome {
+            Ok(()) => eprintln!("{}", "Connection closed by server.".yellow()),
+            Err(e) => eprintln!("{}: {}", "Connection lost".yellow(), e),
+        }
+
+        eprintln!(
+            "{}",
+            format!("Reconnecting in {}s...", backoff.as_secs()).bright_black()
+        );
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Narrows the program's transactions to those that mention any of `addresses`
+struct MentionsFilter {
+    addresses: Vec<String>,
+    lookup: ApiClient,
+}
+
+impl MentionsFilter {
+    async fn matches(&self, logs: &LogsValue) -> Result<bool> {
+        let mentioned = |text: &str| self.addresses.iter().any(|a| text.contains(a.as_str()));
+        if logs.logs.iter().any(|line| mentioned(line)) {
+            return Ok(true);
+        }
+
+        // Logs rarely name plain accounts, so check the transaction's account keys
+        let accounts = self
+            .lookup
+            .get_transaction_accounts(&logs.signature)
+            .await?
+            .unwrap_or_default();
+        Ok(accounts.iter().any(|account| mentioned(account)))
+    }
+}
+
+/// Subscribe to the program and print notifications until the connection drops
+async fn stream_logs(
+    client: &PubsubClient,
+    program_id: &str,
+    filter: Option<&MentionsFilter>,
+    commitment: &str,
+    backoff: &mut Duration,
+) -> Result<()> {
+    let mut connection = client.connect().await?;
+
+    connection
+        .subscribe(
+            "logsSubscribe",
+            serde_json::json!([
+                { "mentions": [program_id] },
+                { "commitment": commitment },
+            ]),
+        )
+        .await?;
+
+    // Only reset the backoff once the subscription is live
+    *backoff = INITIAL_BACKOFF;
+
+    while let Some(notification) = connection.next_notification().await? {
+        let logs: LogsNotification = serde_json::from_value(notification.result)?;
+
+        if let Some(filter) = filter {
+            match filter.matches(&logs.value).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    // A failed lookup shouldn't drop the subscription
+                    eprintln!("{} {}: {}", "Skipped".yellow(), logs.value.signature, e);
+                    continue;
+                }
+            }
+        }
+
+        print_logs(&logs);
+    }
+
+    Ok(())
+}
+
+fn print_logs(logs: &LogsNotification) {
+    let status = match logs.value.err {
+        Some(ref err) => format!("✗ {}", err).red(),
+        None => "✓".green(),
+    };
+
+    println!(
+        "{} {} {}",
+        format!("[slot {}]", logs.context.slot).bright_black(),
+        logs.value.signature.bright_blue(),
+        status
+    );
+
+    for line in &logs.value.logs {
+        if let Some(message) = line.strip_prefix(PROGRAM_LOG_PREFIX) {
+            println!("  {} {}", "›".cyan(), message);
+        }
+    }
+
+    println!();
+}
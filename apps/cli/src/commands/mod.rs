@@ -1,13 +1,25 @@
-pub mod auth;
 pub mod airdrop;
 pub mod apikey;
+pub mod auth;
+pub mod cache;
 pub mod config;
-pub mod rpc;
 pub mod deploy;
+pub mod fees;
+pub mod keys;
+pub mod logs;
+pub mod programs;
+pub mod rpc;
+pub mod wallet;
 
-pub use auth::handle_auth_command;
 pub use airdrop::handle_airdrop_command;
 pub use apikey::handle_apikey_command;
+pub use auth::handle_auth_command;
+pub use cache::handle_cache_command;
 pub use config::handle_config_command;
-pub use rpc::handle_rpc_command;
 pub use deploy::handle_deploy_command;
+pub use fees::handle_fees_command;
+pub use keys::handle_keys_command;
+pub use logs::handle_logs_command;
+pub use programs::handle_programs_command;
+pub use rpc::handle_rpc_command;
+pub use wallet::handle_wallet_command;
@@ -0,0 +1,338 @@
+use crate::address::parse_address;
+use crate::amount::Lamports;
+use crate::auth::CredentialsStore;
+use crate::cli::ProgramsCommands;
+use crate::client::api::Commitment;
+use crate::client::ApiClient;
+use crate::cluster::Cluster;
+use crate::config::manager::ConfigManager;
+use crate::deployer::{self, DeploymentStatus, TokenFee};
+use crate::error::{CliError, Result};
+use crate::export::{export_records, fetch_all, ExportArgs, Page};
+use crate::fees::PriorityFeeArgs;
+use crate::keys;
+use crate::transaction::{sign_and_send, wait_for_confirmation};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use solana_signer::Signer;
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Offset of `decimals` in an SPL token mint account
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+pub async fn handle_programs_command(
+    command: ProgramsCommands,
+    api_url: &str,
+    cluster: Cluster,
+) -> Result<()> {
+    match command {
+        ProgramsCommands::List {
+            limit,
+            offset,
+            all,
+            export,
+        } => list_programs(api_url, limit, offset, all, export).await,
+        ProgramsCommands::Extend {
+            program,
+            days,
+            pay_in_token,
+            keypair,
+            yes,
+            timeout,
+            fee,
+        } => {
+            let options = ExtendOptions {
+                days,
+                pay_in_token,
+                keypair,
+                yes,
+                timeout: Duration::from_secs(timeout),
+                fee,
+            };
+            extend_hosting(api_url, cluster, &program, options).await
+        }
+    }
+}
+
+struct ExtendOptions {
+    days: u16,
+    pay_in_token: bool,
+    keypair: Option<String>,
+    yes: bool,
+    timeout: Duration,
+    fee: PriorityFeeArgs,
+}
+
+async fn extend_hosting(
+    api_url: &str,
+    cluster: Cluster,
+    program: &str,
+    options: ExtendOptions,
+) -> Result<()> {
+    let program = parse_address(program)?;
+
+    // Load config for timeout and the default signer
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+
+    let payer = match options.keypair.as_deref() {
+        Some(reference) => keys::read_keypair(&keys::resolve_keypair_path(reference)?)?,
+        None => keys::default_signer(&config)?,
+    };
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+
+    // Create API client
+    let client = ApiClient::new(api_url.to_string(), timeout)?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    let deployer_config = deployer::fetch_config(&client).await?.ok_or_else(|| {
+        CliError::InvalidInput(format!("The program deployer is not set up on {}", cluster))
+    })?;
+
+    let deployment_address = deployer::deployment_address(&program);
+    let deployment = deployer::fetch_deployment(&client, &program)
+        .await?
+        .ok_or_else(|| {
+            CliError::InvalidInput(format!("{} is not hosted by the program deployer", program))
+        })?;
+
+    let now = Utc::now().timestamp();
+    if deployment.status != DeploymentStatus::Hosted || deployment.expires_at <= now {
+        return Err(CliError::InvalidInput(format!(
+            "{} is no longer hosted and cannot be extended",
+            program
+        )));
+    }
+
+    let expires_at = deployment.expires_at + i64::from(options.days) * SECONDS_PER_DAY;
+    let latest = deployment.hosted_at + deployer_config.max_hosting_period;
+    if expires_at > latest {
+        let remaining = (latest - deployment.expires_at).max(0) / SECONDS_PER_DAY;
+        return Err(CliError::InvalidInput(format!(
+            "Hosting can be extended by at most {} more day(s), until {}",
+            remaining,
+            format_timestamp(latest)
+        )));
+    }
+
+    let days = u64::from(options.days);
+    let (token_fee, fee) = if options.pay_in_token {
+        let mint = deployer_config.fee_mint.ok_or_else(|| {
+            CliError::InvalidInput(format!(
+                "The program deployer on {} does not accept token fees",
+                cluster
+            ))
+        })?;
+        let mint_account = client
+            .get_account_info(&mint.to_string())
+            .await?
+            .ok_or_else(|| CliError::ApiError(format!("Fee mint {} not found", mint)))?;
+        let token_program = parse_address(&mint_account.owner)?;
+        let decimals = mint_account
+            .data
+            .get(MINT_DECIMALS_OFFSET)
+            .copied()
+            .ok_or_else(|| CliError::ApiError(format!("{} is not a token mint", mint)))?;
+
+        let amount = deployer_config.extension_token_fee.saturating_mul(days);
+        (
+            Some(TokenFee::new(mint, &payer.pubkey(), token_program)),
+            format!("{} (mint {})", format_token_amount(amount, decimals), mint),
+        )
+    } else {
+        let amount = Lamports(deployer_config.extension_fee.saturating_mul(days));
+        (None, amount.to_string())
+    };
+
+    println!("{}", "Hosting extension".bold());
+    println!("  Program:         {}", program);
+    println!(
+        "  Current expiry:  {}",
+        format_timestamp(deployment.expires_at)
+    );
+    println!(
+        "  New expiry:      {}",
+        format_timestamp(expires_at).green()
+    );
+    println!("  Fee:             {}", fee.bright_yellow());
+    println!("  Payer:           {}", payer.pubkey());
+    println!();
+
+    if !options.yes {
+        confirm("Send the extension?")?;
+    }
+
+    let compute_budget = options
+        .fee
+        .instructions(&client, &[deployment_address, deployer::treasury_address()])
+        .await?;
+    let extend =
+        deployer::extend_hosting(&program, &payer.pubkey(), options.days, token_fee.as_ref());
+
+    let signature = sign_and_send(&client, &compute_budget, &[extend], &[&payer]).await?;
+    wait_for_confirmation(&client, &signature, Commitment::Confirmed, options.timeout).await?;
+
+    println!("{} Hosting extended!", "✓".green().bold());
+    println!("  Expires:    {}", format_timestamp(expires_at));
+    println!("  Signature:  {}", signature);
+
+    Ok(())
+}
+
+fn confirm(question: &str) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        return Err(CliError::InvalidInput(
+            "Refusing to send without confirmation. Pass --yes to skip the prompt".to_string(),
+        ));
+    }
+
+    print!("{} [y/N] ", question);
+    std::io::stdout().flush().ok();
+
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .map_err(|e| CliError::Other(format!("Failed to read answer: {}", e)))?;
+
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(()),
+        _ => Err(CliError::Cancelled),
+    }
+}
+
+pub(crate) fn format_timestamp(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0)
+        .map(|time| time.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+fn format_token_amount(amount: u64, decimals: u8) -> String {
+    let scale = 10u128.pow(u32::from(decimals));
+    let whole = u128::from(amount) / scale;
+    let fraction = u128::from(amount) % scale;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{:0width$}", fraction, width = usize::from(decimals));
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+async fn list_programs(
+    api_url: &str,
+    limit: u32,
+    offset: u32,
+    all: bool,
+    export: ExportArgs,
+) -> Result<()> {
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+
+    // Load config for timeout and explorer links
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+
+    // Create API client
+    let client = ApiClient::new(api_url.to_string(), timeout)?.with_credentials(credentials);
+
+    // Show spinner
+    let spinner = indicatif::ProgressBar::new_spinner();
+    spinner.set_message("Fetching programs...");
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    // The endpoint returns a bare array, so a short page means the end
+    let programs = if all {
+        fetch_all(
+            limit,
+            offset,
+            |limit, offset| {
+                let client = &client;
+                async move {
+                    let items = client.list_programs(limit, offset).await?;
+                    let has_more = items.len() as u32 == limit;
+                    Ok(Page { items, has_more })
+                }
+            },
+            |_| true,
+        )
+        .await?
+    } else {
+        client.list_programs(limit, offset).await?
+    };
+
+    spinner.finish_and_clear();
+
+    if let Some(format) = export.export {
+        return export_records(&programs, format, export.out.as_deref());
+    }
+
+    if programs.is_empty() {
+        println!("{}", "No programs found.".yellow());
+        println!();
+        println!("Deploy one with: sdt deploy program <path-to-program.so>");
+        return Ok(());
+    }
+
+    // Create table
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Name").fg(Color::Cyan),
+        Cell::new("Program ID").fg(Color::Cyan),
+        Cell::new("Cluster").fg(Color::Cyan),
+        Cell::new("Status").fg(Color::Cyan),
+        Cell::new("Expires").fg(Color::Cyan),
+    ]);
+
+    for program in &programs {
+        let status_cell = match program.status.as_str() {
+            "deployed" | "claimed" => Cell::new(&program.status).fg(Color::Green),
+            "pending" => Cell::new(&program.status).fg(Color::Yellow),
+            "expired" | "failed" => Cell::new(&program.status).fg(Color::Red),
+            _ => Cell::new(&program.status),
+        };
+
+        // Claimed programs no longer expire
+        let expires = match (&program.claimed_at, &program.expires_at) {
+            (Some(_), _) => "-".to_string(),
+            (None, Some(expires_at)) => expires_at
+                .split('T')
+                .next()
+                .unwrap_or(expires_at)
+                .to_string(),
+            (None, None) => "-".to_string(),
+        };
+
+        table.add_row(vec![
+            Cell::new(&program.name),
+            Cell::new(&program.program_address),
+            Cell::new(&program.cluster),
+            status_cell,
+            Cell::new(expires),
+        ]);
+    }
+
+    println!();
+    println!("{}", table);
+    println!();
+
+    if !all && programs.len() as u32 == limit {
+        println!(
+            "{}",
+            format!(
+                "Use --offset {} to see more, or --all to fetch everything",
+                offset + limit
+            )
+            .bright_black()
+        );
+        println!();
+    }
+
+    Ok(())
+}
@@ -1,46 +1,96 @@
 use crate::auth::CredentialsStore;
 use crate::cli::RpcCommands;
 use crate::client::api::ApiClient;
-use crate::error::Result;
+use crate::client::RpcCache;
+use crate::cluster::Cluster;
+use crate::config::manager::ConfigManager;
+use crate::error::{CliError, Result};
 use colored::Colorize;
 use comfy_table::{Cell, Table};
-use std::time::Duration;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-pub async fn handle_rpc_command(command: RpcCommands, api_url: &str) -> Result<()> {
+pub async fn handle_rpc_command(
+    command: RpcCommands,
+    api_url: &str,
+    cluster: Cluster,
+) -> Result<()> {
     match command {
-        RpcCommands::Call { method, params } => handle_rpc_call(method, params, api_url).await,
-        RpcCommands::Info => handle_rpc_info(api_url).await,
+        RpcCommands::Call {
+            method,
+            params,
+            no_cache,
+        } => handle_rpc_call(method, params, api_url, cluster, no_cache).await,
+        RpcCommands::Info => handle_rpc_info(api_url, cluster).await,
+        RpcCommands::Bench {
+            methods,
+            concurrency,
+            duration,
+            account,
+            export,
+        } => {
+            // Repeated --method values would split one method's samples across rows
+            let mut unique = Vec::with_capacity(methods.len());
+            for method in methods {
+                if !unique.contains(&method) {
+                    unique.push(method);
+                }
+            }
+
+            let options = BenchOptions {
+                methods: unique,
+                concurrency,
+                duration: Duration::from_secs(duration),
+                account,
+            };
+            handle_rpc_bench(options, export, api_url, cluster).await
+        }
     }
 }
 
-async fn handle_rpc_call(method: String, params: Option<String>, api_url: &str) -> Result<()> {
+async fn handle_rpc_call(
+    method: String,
+    params: Option<String>,
+    api_url: &str,
+    cluster: Cluster,
+    no_cache: bool,
+) -> Result<()> {
     // Load credentials
     let credentials = CredentialsStore::load()?;
 
     // Parse params if provided
     let parsed_params = if let Some(params_str) = params {
-        let value: serde_json::Value = serde_json::from_str(&params_str)
-            .map_err(|e| crate::error::CliError::InvalidInput(format!("Invalid JSON params: {}", e)))?;
+        let value: serde_json::Value = serde_json::from_str(&params_str).map_err(|e| {
+            crate::error::CliError::InvalidInput(format!("Invalid JSON params: {}", e))
+        })?;
         Some(value)
     } else {
         None
     };
 
     // Create API client
-    let client = ApiClient::new(api_url.to_string(), Duration::from_secs(30))?
-        .with_credentials(credentials);
+    let mut client = ApiClient::new(api_url.to_string(), Duration::from_secs(30))?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    // Serve cacheable reads from disk when enabled
+    let config = ConfigManager::new()?.load()?;
+    if config.cache.enabled && !no_cache {
+        client = client.with_cache(RpcCache::open(
+            Duration::from_secs(config.cache.ttl_seconds),
+            cluster,
+        )?);
+    }
 
     // Send RPC request
     let response = client.send_rpc_request(&method, parsed_params).await?;
 
     // Check for RPC error
     if let Some(error) = response.error {
-        eprintln!(
-            "{}: [{}] {}",
-            "RPC Error".red(),
-            error.code,
-            error.message
-        );
+        eprintln!("{}: [{}] {}", "RPC Error".red(), error.code, error.message);
         return Err(crate::error::CliError::Other(error.message));
     }
 
@@ -54,13 +104,14 @@ async fn handle_rpc_call(method: String, params: Option<String>, api_url: &str)
     Ok(())
 }
 
-async fn handle_rpc_info(api_url: &str) -> Result<()> {
+async fn handle_rpc_info(api_url: &str, cluster: Cluster) -> Result<()> {
     // Load credentials
     let credentials = CredentialsStore::load()?;
 
     // Create API client
     let client = ApiClient::new(api_url.to_string(), Duration::from_secs(30))?
-        .with_credentials(credentials);
+        .with_credentials(credentials)
+        .with_cluster(cluster);
 
     // Get provider info
     let info = client.get_rpc_info().await?;
@@ -72,6 +123,8 @@ async fn handle_rpc_info(api_url: &str) -> Result<()> {
         Cell::new("Value").fg(comfy_table::Color::Cyan),
     ]);
 
+    table.add_row(vec![Cell::new("Cluster"), Cell::new(cluster)]);
+
     table.add_row(vec![
         Cell::new("Total Providers"),
         Cell::new(info.total.to_string()),
@@ -86,3 +139,360 @@ async fn handle_rpc_info(api_url: &str) -> Result<()> {
 
     Ok(())
 }
+
+struct BenchOptions {
+    methods: Vec<String>,
+    concurrency: usize,
+    duration: Duration,
+    account: String,
+}
+
+/// Outcome of a single benchmark request
+struct BenchSample {
+    method: String,
+    latency: Duration,
+    finished_at: Duration,
+    /// Slot returned by getSlot, used for lag calculation
+    slot: Option<u64>,
+    /// Error category (JSON-RPC code, HTTP status or transport failure)
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    cluster: Cluster,
+    started_at: u64,
+    duration_seconds: f64,
+    concurrency: usize,
+    total_requests: usize,
+    failed_requests: usize,
+    error_rate: f64,
+    requests_per_second: f64,
+    latency: LatencyStats,
+    methods: Vec<MethodReport>,
+    errors: BTreeMap<String, usize>,
+    slot_lag: SlotLagStats,
+}
+
+#[derive(Debug, Serialize)]
+struct MethodReport {
+    method: String,
+    requests: usize,
+    errors: usize,
+    latency: LatencyStats,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct LatencyStats {
+    p50_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+    mean_ms: f64,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct SlotLagStats {
+    samples: usize,
+    max: u64,
+    mean: f64,
+}
+
+async fn handle_rpc_bench(
+    options: BenchOptions,
+    export: Option<PathBuf>,
+    api_url: &str,
+    cluster: Cluster,
+) -> Result<()> {
+    if options.concurrency == 0 {
+        return Err(CliError::InvalidInput(
+            "Concurrency must be at least 1".to_string(),
+        ));
+    }
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+
+    // Create API client shared by all workers
+    let client = Arc::new(
+        ApiClient::new(api_url.to_string(), Duration::from_secs(30))?
+            .with_credentials(credentials)
+            .with_cluster(cluster),
+    );
+
+    let spinner = indicatif::ProgressBar::new_spinner();
+    spinner.set_message(format!(
+        "Benchmarking {} on {} for {}s with {} workers...",
+        options.methods.join(", "),
+        cluster,
+        options.duration.as_secs(),
+        options.concurrency
+    ));
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let start = Instant::now();
+    let options = Arc::new(options);
+
+    let workers: Vec<_> = (0..options.concurrency)
+        .map(|worker| {
+            let client = Arc::clone(&client);
+            let options = Arc::clone(&options);
+            tokio::spawn(async move { run_bench_worker(worker, &client, &options, start).await })
+        })
+        .collect();
+
+    let mut samples = Vec::new();
+    for worker in workers {
+        let worker_samples = worker
+            .await
+            .map_err(|e| CliError::Other(format!("Benchmark worker failed: {}", e)))?;
+        samples.extend(worker_samples);
+    }
+
+    spinner.finish_and_clear();
+
+    let report = build_bench_report(samples, &options, cluster, started_at, start.elapsed());
+    print_bench_report(&report);
+
+    if let Some(path) = export {
+        let json = serde_json::to_string_pretty(&report)?;
+        std::fs::write(&path, json)
+            .map_err(|e| CliError::Other(format!("Failed to write {}: {}", path.display(), e)))?;
+        println!(
+            "Report written to {}",
+            path.display().to_string().bright_black()
+        );
+        println!();
+    }
+
+    Ok(())
+}
+
+async fn run_bench_worker(
+    worker: usize,
+    client: &ApiClient,
+    options: &BenchOptions,
+    start: Instant,
+) -> Vec<BenchSample> {
+    let mut samples = Vec::new();
+    let mut iteration = worker;
+
+    while start.elapsed() < options.duration {
+        let method = &options.methods[iteration % options.methods.len()];
+        iteration += 1;
+
+        let params = match method.as_str() {
+            "getAccountInfo" => Some(serde_json::json!([
+                options.account,
+                { "encoding": "base64" }
+            ])),
+            _ => None,
+        };
+
+        let sent_at = Instant::now();
+        let response = client.send_rpc_request(method, params).await;
+        let latency = sent_at.elapsed();
+
+        let (slot, error) = match response {
+            Ok(response) => match response.error {
+                Some(error) => (None, Some(format!("rpc {}", error.code))),
+                None => (response.result.and_then(|r| r.as_u64()), None),
+            },
+            Err(CliError::ApiResponseError { status, .. }) => {
+                (None, Some(format!("http {}", status)))
+            }
+            Err(CliError::NetworkError(e)) if e.is_timeout() => (None, Some("timeout".to_string())),
+            Err(_) => (None, Some("network".to_string())),
+        };
+
+        samples.push(BenchSample {
+            method: method.clone(),
+            latency,
+            finished_at: start.elapsed(),
+            slot: if method == "getSlot" { slot } else { None },
+            error,
+        });
+    }
+
+    samples
+}
+
+fn build_bench_report(
+    mut samples: Vec<BenchSample>,
+    options: &BenchOptions,
+    cluster: Cluster,
+    started_at: u64,
+    elapsed: Duration,
+) -> BenchReport {
+    samples.sort_by_key(|s| s.finished_at);
+
+    // Slot lag is measured against the highest slot observed so far
+    let mut highest_slot = 0;
+    let mut lags = Vec::new();
+    for slot in samples.iter().filter_map(|s| s.slot) {
+        highest_slot = highest_slot.max(slot);
+        lags.push(highest_slot - slot);
+    }
+
+    let slot_lag = SlotLagStats {
+        samples: lags.len(),
+        max: lags.iter().copied().max().unwrap_or_default(),
+        mean: if lags.is_empty() {
+            0.0
+        } else {
+            lags.iter().sum::<u64>() as f64 / lags.len() as f64
+        },
+    };
+
+    let mut errors = BTreeMap::new();
+    for error in samples.iter().filter_map(|s| s.error.as_ref()) {
+        *errors.entry(error.clone()).or_insert(0) += 1;
+    }
+
+    let methods = options
+        .methods
+        .iter()
+        .map(|method| {
+            let method_samples: Vec<&BenchSample> =
+                samples.iter().filter(|s| &s.method == method).collect();
+            MethodReport {
+                method: method.clone(),
+                requests: method_samples.len(),
+                errors: method_samples.iter().filter(|s| s.error.is_some()).count(),
+                latency: latency_stats(method_samples.iter().map(|s| s.latency).collect()),
+            }
+        })
+        .collect();
+
+    let failed_requests = samples.iter().filter(|s| s.error.is_some()).count();
+    let total_requests = samples.len();
+
+    BenchReport {
+        cluster,
+        started_at,
+        duration_seconds: elapsed.as_secs_f64(),
+        concurrency: options.concurrency,
+        total_requests,
+        failed_requests,
+        error_rate: if total_requests == 0 {
+            0.0
+        } else {
+            failed_requests as f64 / total_requests as f64
+        },
+        requests_per_second: total_requests as f64 / elapsed.as_secs_f64(),
+        latency: latency_stats(samples.iter().map(|s| s.latency).collect()),
+        methods,
+        errors,
+        slot_lag,
+    }
+}
+
+fn latency_stats(mut latencies: Vec<Duration>) -> LatencyStats {
+    if latencies.is_empty() {
+        return LatencyStats::default();
+    }
+
+    latencies.sort();
+
+    // Nearest-rank percentile
+    let percentile = |p: f64| {
+        let rank = ((p / 100.0) * latencies.len() as f64).ceil() as usize;
+        as_millis(latencies[rank.clamp(1, latencies.len()) - 1])
+    };
+
+    let total: Duration = latencies.iter().sum();
+
+    LatencyStats {
+        p50_ms: percentile(50.0),
+        p95_ms: percentile(95.0),
+        p99_ms: percentile(99.0),
+        max_ms: as_millis(latencies[latencies.len() - 1]),
+        mean_ms: as_millis(total) / latencies.len() as f64,
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn print_bench_report(report: &BenchReport) {
+    println!();
+    println!("{}", "RPC Benchmark".bold());
+    println!("{}", "=".repeat(40));
+    println!();
+    println!("  Cluster:    {}", report.cluster);
+    println!(
+        "  Requests:   {} in {:.1}s ({:.1} req/s, {} workers)",
+        report.total_requests,
+        report.duration_seconds,
+        report.requests_per_second,
+        report.concurrency
+    );
+    println!(
+        "  Errors:     {} ({:.2}%)",
+        report.failed_requests,
+        report.error_rate * 100.0
+    );
+    println!(
+        "  Slot lag:   max {} / mean {:.2} over {} getSlot samples",
+        report.slot_lag.max, report.slot_lag.mean, report.slot_lag.samples
+    );
+    println!();
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Method").fg(comfy_table::Color::Cyan),
+        Cell::new("Requests").fg(comfy_table::Color::Cyan),
+        Cell::new("Errors").fg(comfy_table::Color::Cyan),
+        Cell::new("p50 (ms)").fg(comfy_table::Color::Cyan),
+        Cell::new("p95 (ms)").fg(comfy_table::Color::Cyan),
+        Cell::new("p99 (ms)").fg(comfy_table::Color::Cyan),
+        Cell::new("Max (ms)").fg(comfy_table::Color::Cyan),
+    ]);
+
+    let rows = report
+        .methods
+        .iter()
+        .map(|m| (m.method.as_str(), m.requests, m.errors, &m.latency))
+        .chain(std::iter::once((
+            "all",
+            report.total_requests,
+            report.failed_requests,
+            &report.latency,
+        )));
+
+    for (method, requests, errors, latency) in rows {
+        table.add_row(vec![
+            Cell::new(method),
+            Cell::new(requests),
+            Cell::new(errors),
+            Cell::new(format!("{:.1}", latency.p50_ms)),
+            Cell::new(format!("{:.1}", latency.p95_ms)),
+            Cell::new(format!("{:.1}", latency.p99_ms)),
+            Cell::new(format!("{:.1}", latency.max_ms)),
+        ]);
+    }
+
+    println!("{}", table);
+    println!();
+
+    if !report.errors.is_empty() {
+        let mut errors = Table::new();
+        errors.set_header(vec![
+            Cell::new("Error").fg(comfy_table::Color::Cyan),
+            Cell::new("Count").fg(comfy_table::Color::Cyan),
+        ]);
+        for (error, count) in &report.errors {
+            errors.add_row(vec![
+                Cell::new(error).fg(comfy_table::Color::Red),
+                Cell::new(count),
+            ]);
+        }
+        println!("{}", errors);
+        println!();
+    }
+}
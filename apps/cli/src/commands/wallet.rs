@@ -0,0 +1,188 @@
+use crate::address::resolve_address;
+use crate::auth::CredentialsStore;
+use crate::cli::{BalanceSort, WalletCommands};
+use crate::client::api::{Commitment, TokenBalance};
+use crate::client::ApiClient;
+use crate::cluster::Cluster;
+use crate::config::manager::ConfigManager;
+use crate::config::OutputFormat;
+use crate::error::Result;
+use colored::Colorize;
+use comfy_table::{Cell, CellAlignment, Color, Table};
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::time::Duration;
+
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+/// A token holding along with the token program that owns it
+#[derive(Debug, Serialize)]
+struct TokenHolding {
+    program: &'static str,
+    #[serde(flatten)]
+    balance: TokenBalance,
+}
+
+#[derive(Debug, Serialize)]
+struct WalletBalances {
+    address: String,
+    cluster: Cluster,
+    sol: String,
+    lamports: u64,
+    tokens: Vec<TokenHolding>,
+}
+
+pub async fn handle_wallet_command(
+    command: WalletCommands,
+    api_url: &str,
+    cluster: Cluster,
+    output: OutputFormat,
+) -> Result<()> {
+    match command {
+        WalletCommands::Balances {
+            address,
+            limit,
+            sort,
+        } => show_balances(api_url, cluster, output, address.as_deref(), limit, sort).await,
+    }
+}
+
+async fn show_balances(
+    api_url: &str,
+    cluster: Cluster,
+    output: OutputFormat,
+    address: Option<&str>,
+    limit: Option<u32>,
+    sort: BalanceSort,
+) -> Result<()> {
+    // Load config for timeout and default keypair
+    let config = ConfigManager::new()?.load()?;
+    let timeout = Duration::from_secs(config.api.timeout_seconds);
+
+    let address = resolve_address(address, &config)?.to_string();
+
+    // Load credentials
+    let credentials = CredentialsStore::load()?;
+
+    // Create API client
+    let client = ApiClient::new(api_url.to_string(), timeout)?
+        .with_credentials(credentials)
+        .with_cluster(cluster);
+
+    // Show spinner
+    let spinner = indicatif::ProgressBar::new_spinner();
+    spinner.set_message("Fetching balances...");
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    // The wallet service only covers the original token program. --limit is
+    // applied locally so it keeps the top holdings after sorting.
+    let (sol, spl, token_2022) = tokio::try_join!(
+        client.get_balance(&address, Commitment::Confirmed),
+        client.get_token_balances(&address),
+        client.get_token_accounts_by_owner(&address, TOKEN_2022_PROGRAM_ID),
+    )?;
+
+    spinner.finish_and_clear();
+
+    let mut tokens: Vec<TokenHolding> = spl
+        .balances
+        .into_iter()
+        .map(|balance| TokenHolding {
+            program: "spl-token",
+            balance,
+        })
+        .chain(token_2022.into_iter().map(|balance| TokenHolding {
+            program: "spl-token-2022",
+            balance,
+        }))
+        .collect();
+
+    tokens.sort_by(|a, b| compare_holdings(a, b, sort));
+    if let Some(limit) = limit {
+        tokens.truncate(limit as usize);
+    }
+
+    let balances = WalletBalances {
+        address,
+        cluster,
+        sol: sol.to_sol_string(),
+        lamports: sol.0,
+        tokens,
+    };
+
+    if output == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&balances)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", "Wallet Balances".bold());
+    println!("{}", "=".repeat(40));
+    println!();
+    println!("  Address: {}", balances.address.bright_cyan());
+    println!("  Cluster: {}", balances.cluster.to_string().bright_cyan());
+    println!("  SOL:     {}", sol.to_string().bright_yellow());
+    println!();
+
+    if balances.tokens.is_empty() {
+        println!("{}", "No token holdings found.".yellow());
+        println!();
+        return Ok(());
+    }
+
+    // Create table
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Mint").fg(Color::Cyan),
+        Cell::new("Symbol").fg(Color::Cyan),
+        Cell::new("Amount").fg(Color::Cyan),
+        Cell::new("Decimals").fg(Color::Cyan),
+        Cell::new("USD Value").fg(Color::Cyan),
+        Cell::new("Program").fg(Color::Cyan),
+    ]);
+
+    for holding in &balances.tokens {
+        let token = &holding.balance;
+        table.add_row(vec![
+            Cell::new(&token.mint),
+            Cell::new(token.symbol.as_deref().unwrap_or("-")),
+            Cell::new(&token.balance).set_alignment(CellAlignment::Right),
+            Cell::new(token.decimals),
+            Cell::new(
+                token
+                    .usd_value
+                    .map(|value| format!("${:.2}", value))
+                    .unwrap_or_else(|| "-".to_string()),
+            )
+            .set_alignment(CellAlignment::Right),
+            Cell::new(holding.program),
+        ]);
+    }
+
+    println!("{}", table);
+    println!();
+
+    Ok(())
+}
+
+fn compare_holdings(a: &TokenHolding, b: &TokenHolding, sort: BalanceSort) -> Ordering {
+    let (a, b) = (&a.balance, &b.balance);
+    let by_mint = a.mint.cmp(&b.mint);
+
+    match sort {
+        BalanceSort::Mint => by_mint,
+        BalanceSort::Amount => ui_amount(b).total_cmp(&ui_amount(a)).then(by_mint),
+        // Holdings without a USD value sort last
+        BalanceSort::Value => match (a.usd_value, b.usd_value) {
+            (Some(a_value), Some(b_value)) => b_value.total_cmp(&a_value).then(by_mint),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => by_mint,
+        },
+    }
+}
+
+/// Decimals-adjusted amount, only used for ordering
+fn ui_amount(balance: &TokenBalance) -> f64 {
+    balance.balance.parse().unwrap_or_default()
+}
@@ -61,7 +61,42 @@ impl ConfigManager {
                     .parse()
                     .map_err(|_| CliError::InvalidInput("Invalid boolean value".to_string()))?
             }
-            _ => return Err(CliError::InvalidInput(format!("Unknown config key: {}", key))),
+            "rpc.ws_url" => {
+                config.rpc.ws_url = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                }
+            }
+            "cache.enabled" => {
+                config.cache.enabled = value
+                    .parse()
+                    .map_err(|_| CliError::InvalidInput("Invalid boolean value".to_string()))?
+            }
+            "cache.ttl_seconds" => {
+                config.cache.ttl_seconds = value
+                    .parse()
+                    .map_err(|_| CliError::InvalidInput("Invalid TTL value".to_string()))?
+            }
+            "network.cluster" => {
+                config.network.cluster = value.parse().map_err(CliError::InvalidInput)?
+            }
+            "network.explorer" => {
+                config.network.explorer = value.parse().map_err(CliError::InvalidInput)?
+            }
+            "wallet.keypair" => {
+                config.wallet.keypair = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                }
+            }
+            _ => {
+                return Err(CliError::InvalidInput(format!(
+                    "Unknown config key: {}",
+                    key
+                )))
+            }
         }
 
         self.save(&config)?;
@@ -81,7 +116,18 @@ impl ConfigManager {
                 OutputFormat::Text => "text".to_string(),
             },
             "output.color" => config.output.color.to_string(),
-            _ => return Err(CliError::InvalidInput(format!("Unknown config key: {}", key))),
+            "rpc.ws_url" => config.rpc.ws_url.unwrap_or_default(),
+            "cache.enabled" => config.cache.enabled.to_string(),
+            "cache.ttl_seconds" => config.cache.ttl_seconds.to_string(),
+            "network.cluster" => config.network.cluster.to_string(),
+            "network.explorer" => config.network.explorer.to_string(),
+            "wallet.keypair" => config.wallet.keypair.unwrap_or_default(),
+            _ => {
+                return Err(CliError::InvalidInput(format!(
+                    "Unknown config key: {}",
+                    key
+                )))
+            }
         };
 
         Ok(value)
@@ -105,6 +151,30 @@ impl ConfigManager {
             ),
             ("output.format".to_string(), format_str.to_string()),
             ("output.color".to_string(), config.output.color.to_string()),
+            (
+                "rpc.ws_url".to_string(),
+                config.rpc.ws_url.unwrap_or_default(),
+            ),
+            (
+                "cache.enabled".to_string(),
+                config.cache.enabled.to_string(),
+            ),
+            (
+                "cache.ttl_seconds".to_string(),
+                config.cache.ttl_seconds.to_string(),
+            ),
+            (
+                "network.cluster".to_string(),
+                config.network.cluster.to_string(),
+            ),
+            (
+                "network.explorer".to_string(),
+                config.network.explorer.to_string(),
+            ),
+            (
+                "wallet.keypair".to_string(),
+                config.wallet.keypair.unwrap_or_default(),
+            ),
         ])
     }
 
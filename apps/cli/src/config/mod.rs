@@ -1,24 +1,29 @@
+use crate::cluster::{Cluster, Explorer};
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
 pub mod manager;
 
 /// Application configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub api: ApiConfig,
 
     #[serde(default)]
     pub output: OutputConfig,
-}
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            api: ApiConfig::default(),
-            output: OutputConfig::default(),
-        }
-    }
+    #[serde(default)]
+    pub rpc: RpcConfig,
+
+    #[serde(default)]
+    pub cache: CacheConfig,
+
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    #[serde(default)]
+    pub wallet: WalletConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,7 +62,52 @@ impl Default for OutputConfig {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RpcConfig {
+    /// Websocket endpoint for pubsub subscriptions, required by `sdt logs`
+    #[serde(default)]
+    pub ws_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheConfig {
+    /// Cache RPC responses on disk (opt-in)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Lifetime of cached mutable reads
+    #[serde(default = "default_cache_ttl")]
+    pub ttl_seconds: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_seconds: default_cache_ttl(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Default cluster when --cluster is not given
+    #[serde(default)]
+    pub cluster: Cluster,
+
+    /// Explorer used for transaction and account links
+    #[serde(default)]
+    pub explorer: Explorer,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WalletConfig {
+    /// Default signer: a keypair file or `sdt keys` name (defaults to ~/.config/solana/id.json)
+    #[serde(default)]
+    pub keypair: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     Table,
@@ -80,3 +130,7 @@ fn default_format() -> OutputFormat {
 fn default_color() -> bool {
     true
 }
+
+fn default_cache_ttl() -> u64 {
+    30
+}
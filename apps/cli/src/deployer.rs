@@ -0,0 +1,557 @@
+//! Client for the on-chain `program-deployer` program that hosts programs
+
+use crate::client::ApiClient;
+use crate::error::{CliError, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_loader_v3_interface::get_program_data_address;
+use solana_pubkey::Pubkey;
+
+/// Address of the `program-deployer` program
+pub const PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("54ER83fowDBHn76bJm9bCMC2gTXb8RcvMgdNYkrkcngp");
+
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::from_str_const("11111111111111111111111111111111");
+const LOADER_ID: Pubkey = Pubkey::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+const SYSVAR_RENT_ID: Pubkey =
+    Pubkey::from_str_const("SysvarRent111111111111111111111111111111111");
+const SYSVAR_CLOCK_ID: Pubkey =
+    Pubkey::from_str_const("SysvarC1ock11111111111111111111111111111111");
+const SYSVAR_INSTRUCTIONS_ID: Pubkey =
+    Pubkey::from_str_const("Sysvar1nstructions1111111111111111111111111");
+const ED25519_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("Ed25519SigVerify111111111111111111111111111");
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+const CONFIG_SEED: &[u8] = b"config";
+const TREASURY_SEED: &[u8] = b"treasury";
+const DEPLOYMENT_SEED: &[u8] = b"deployment";
+const AUTHORITY_SEED: &[u8] = b"authority";
+const USER_QUOTA_SEED: &[u8] = b"quota";
+const NONCE_SEED: &[u8] = b"nonce";
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Prefix of the messages the API signs for attestations
+const ATTESTATION_DOMAIN: &[u8] = b"program-deployer:attestation:v1";
+
+/// Global hosting settings
+#[derive(Debug, Clone, BorshDeserialize)]
+pub struct DeployerConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub attestation_key: Pubkey,
+    pub hosting_duration: i64,
+    pub max_program_size: u32,
+    pub max_deployments_per_user: u16,
+    pub max_hosted_bytes_per_user: u64,
+    pub quota_window: i64,
+    pub max_sponsored_lamports_per_user: u64,
+    pub claim_fee: u64,
+    pub reclaim_reward: u64,
+    pub max_hosting_period: i64,
+    pub extension_fee: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub extension_token_fee: u64,
+    pub paused: bool,
+    pub bump: u8,
+    pub treasury_bump: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize)]
+pub enum DeploymentStatus {
+    Registered,
+    Hosted,
+    Claimed,
+    Reclaimed,
+}
+
+/// On-chain record of a hosted program
+#[derive(Debug, Clone, BorshDeserialize)]
+pub struct HostedDeployment {
+    pub deployer: Pubkey,
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+    pub program_size: u32,
+    pub program_hash: [u8; 32],
+    pub deploy_slot: u64,
+    pub hosted_at: i64,
+    pub expires_at: i64,
+    pub status: DeploymentStatus,
+    pub rent_sponsored: u64,
+    pub upgrade_count: u32,
+    pub last_upgrade_slot: u64,
+    pub last_buffer_hash: [u8; 32],
+    pub bump: u8,
+}
+
+impl DeployerConfig {
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_account("DeployerConfig", data)
+    }
+}
+
+impl HostedDeployment {
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_account("HostedDeployment", data)
+    }
+}
+
+/// The instruction an attestation is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttestationAction {
+    Register,
+    Upgrade,
+}
+
+/// What the API approves for a single registration or upgrade
+#[derive(Debug, Clone, BorshSerialize)]
+pub struct DeployAttestation {
+    pub user: Pubkey,
+    pub action: AttestationAction,
+    /// Program being registered or upgraded
+    pub program: Pubkey,
+    /// SHA-256 of the program bytes
+    pub program_hash: [u8; 32],
+    pub program_size: u32,
+    /// Unix timestamp after which the attestation is no longer accepted
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+/// A [`DeployAttestation`] with the API's ed25519 signature over it
+#[derive(Debug, Clone)]
+pub struct SignedAttestation {
+    pub attestation: DeployAttestation,
+    pub signer: Pubkey,
+    pub signature: [u8; 64],
+}
+
+impl SignedAttestation {
+    /// Ed25519Program instruction verifying the signature, which must directly
+    /// precede `register_deployment` or `upgrade_hosted`
+    pub fn verify_instruction(&self) -> Instruction {
+        // Header, then one set of offsets pointing into this instruction's data
+        const HEADER_LEN: u16 = 2 + 14;
+        const PUBKEY_OFFSET: u16 = HEADER_LEN;
+        const SIGNATURE_OFFSET: u16 = PUBKEY_OFFSET + 32;
+        const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+        const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+        let mut message = ATTESTATION_DOMAIN.to_vec();
+        self.attestation
+            .serialize(&mut message)
+            .expect("writing to a Vec cannot fail");
+
+        let mut data = vec![1, 0];
+        for field in [
+            SIGNATURE_OFFSET,
+            CURRENT_INSTRUCTION,
+            PUBKEY_OFFSET,
+            CURRENT_INSTRUCTION,
+            MESSAGE_OFFSET,
+            message.len() as u16,
+            CURRENT_INSTRUCTION,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(self.signer.as_ref());
+        data.extend_from_slice(&self.signature);
+        data.extend_from_slice(&message);
+
+        Instruction {
+            program_id: ED25519_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        }
+    }
+}
+
+/// Fetch the global config, or `None` if the deployer isn't set up on the cluster
+pub async fn fetch_config(client: &ApiClient) -> Result<Option<DeployerConfig>> {
+    fetch_account(client, &config_address())
+        .await?
+        .map(|data| DeployerConfig::decode(&data))
+        .transpose()
+}
+
+/// Fetch the `HostedDeployment` record for `program`, if it has one
+pub async fn fetch_deployment(
+    client: &ApiClient,
+    program: &Pubkey,
+) -> Result<Option<HostedDeployment>> {
+    fetch_account(client, &deployment_address(program))
+        .await?
+        .map(|data| HostedDeployment::decode(&data))
+        .transpose()
+}
+
+async fn fetch_account(client: &ApiClient, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+    Ok(client
+        .get_account_info(&address.to_string())
+        .await?
+        .map(|account| account.data))
+}
+
+/// Token accounts for paying an extension fee in the configured fee mint
+pub struct TokenFee {
+    pub mint: Pubkey,
+    pub payer_token_account: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl TokenFee {
+    /// Pay from `payer`'s associated token account into the treasury's
+    pub fn new(mint: Pubkey, payer: &Pubkey, token_program: Pubkey) -> Self {
+        Self {
+            mint,
+            payer_token_account: associated_token_address(payer, &mint, &token_program),
+            treasury_token_account: associated_token_address(
+                &treasury_address(),
+                &mint,
+                &token_program,
+            ),
+            token_program,
+        }
+    }
+}
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &PROGRAM_ID).0
+}
+
+pub fn treasury_address() -> Pubkey {
+    Pubkey::find_program_address(&[TREASURY_SEED], &PROGRAM_ID).0
+}
+
+/// Address of the `HostedDeployment` record for `program`
+pub fn deployment_address(program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[DEPLOYMENT_SEED, program.as_ref()], &PROGRAM_ID).0
+}
+
+/// PDA that holds upgrade authority over hosted programs
+pub fn authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[AUTHORITY_SEED], &PROGRAM_ID).0
+}
+
+fn user_quota_address(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[USER_QUOTA_SEED, user.as_ref()], &PROGRAM_ID).0
+}
+
+fn used_nonce_address(nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(&[NONCE_SEED, &nonce.to_le_bytes()], &PROGRAM_ID).0
+}
+
+fn event_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &PROGRAM_ID).0
+}
+
+/// `extend_hosting`: push back `program`'s expiry by `days`, paid by `payer`
+/// in SOL, or in tokens when `token_fee` is given
+pub fn extend_hosting(
+    program: &Pubkey,
+    payer: &Pubkey,
+    days: u16,
+    token_fee: Option<&TokenFee>,
+) -> Instruction {
+    // Anchor expects the program id in place of an omitted optional account
+    let optional =
+        |key: Option<Pubkey>| AccountMeta::new_readonly(key.unwrap_or(PROGRAM_ID), false);
+    let optional_mut = |key: Option<Pubkey>| match key {
+        Some(key) => AccountMeta::new(key, false),
+        None => AccountMeta::new_readonly(PROGRAM_ID, false),
+    };
+
+    let mut data = instruction_discriminator("extend_hosting").to_vec();
+    data.extend_from_slice(&days.to_le_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new(deployment_address(program), false),
+            AccountMeta::new(treasury_address(), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            optional(token_fee.map(|fee| fee.mint)),
+            optional_mut(token_fee.map(|fee| fee.payer_token_account)),
+            optional_mut(token_fee.map(|fee| fee.treasury_token_account)),
+            optional(token_fee.map(|fee| fee.token_program)),
+            AccountMeta::new_readonly(event_authority_address(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// `register_deployment`: record that `deployer` will deploy the attested
+/// bytes to `program` from `buffer`
+///
+/// Must directly follow [`SignedAttestation::verify_instruction`].
+pub fn register_deployment(
+    program: &Pubkey,
+    buffer: &Pubkey,
+    deployer: &Pubkey,
+    attestation: &DeployAttestation,
+) -> Instruction {
+    let mut data = instruction_discriminator("register_deployment").to_vec();
+    data.extend_from_slice(program.as_ref());
+    data.extend_from_slice(buffer.as_ref());
+    data.extend_from_slice(&attestation.program_size.to_le_bytes());
+    data.extend_from_slice(&attestation.program_hash);
+    data.extend_from_slice(&attestation.expiry.to_le_bytes());
+    data.extend_from_slice(&attestation.nonce.to_le_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new(deployment_address(program), false),
+            AccountMeta::new(user_quota_address(deployer), false),
+            AccountMeta::new(used_nonce_address(attestation.nonce), false),
+            AccountMeta::new(*deployer, true),
+            AccountMeta::new_readonly(SYSVAR_INSTRUCTIONS_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(event_authority_address(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// `fund_buffer`: create `program`'s registered buffer with treasury-sponsored
+/// rent; `buffer` must sign
+pub fn fund_buffer(program: &Pubkey, buffer: &Pubkey, deployer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new_readonly(deployment_address(program), false),
+            AccountMeta::new(treasury_address(), false),
+            AccountMeta::new_readonly(authority_address(), false),
+            AccountMeta::new(*buffer, true),
+            AccountMeta::new_readonly(*deployer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(LOADER_ID, false),
+        ],
+        data: instruction_discriminator("fund_buffer").to_vec(),
+    }
+}
+
+/// `write_buffer`: write `bytes` at `offset` into `program`'s sponsored buffer
+pub fn write_buffer(
+    program: &Pubkey,
+    buffer: &Pubkey,
+    deployer: &Pubkey,
+    offset: u32,
+    bytes: &[u8],
+) -> Instruction {
+    let mut data = instruction_discriminator("write_buffer").to_vec();
+    data.extend_from_slice(&offset.to_le_bytes());
+    data.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    data.extend_from_slice(bytes);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(deployment_address(program), false),
+            AccountMeta::new_readonly(authority_address(), false),
+            AccountMeta::new(*buffer, false),
+            AccountMeta::new_readonly(*deployer, true),
+            AccountMeta::new_readonly(LOADER_ID, false),
+        ],
+        data,
+    }
+}
+
+/// `deploy_program`: deploy the registered buffer to `program`, which must
+/// sign, with the authority PDA as its upgrade authority; `deployer` pays for
+/// the program account and the treasury for its programdata
+pub fn deploy_program(program: &Pubkey, buffer: &Pubkey, deployer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new(deployment_address(program), false),
+            AccountMeta::new(treasury_address(), false),
+            AccountMeta::new_readonly(authority_address(), false),
+            AccountMeta::new(*program, true),
+            AccountMeta::new(get_program_data_address(program), false),
+            AccountMeta::new(*buffer, false),
+            AccountMeta::new(*deployer, true),
+            AccountMeta::new_readonly(SYSVAR_RENT_ID, false),
+            AccountMeta::new_readonly(SYSVAR_CLOCK_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(LOADER_ID, false),
+            AccountMeta::new_readonly(event_authority_address(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+        data: instruction_discriminator("deploy_program").to_vec(),
+    }
+}
+
+/// `upgrade_hosted`: replace `program`'s code with `buffer`, whose authority
+/// must already be [`authority_address`]; the buffer's lamports go to `deployer`
+///
+/// Must directly follow [`SignedAttestation::verify_instruction`] for the
+/// buffer's bytes.
+pub fn upgrade_hosted(
+    program: &Pubkey,
+    buffer: &Pubkey,
+    deployer: &Pubkey,
+    attestation: &DeployAttestation,
+) -> Instruction {
+    let mut data = instruction_discriminator("upgrade_hosted").to_vec();
+    data.extend_from_slice(&attestation.program_size.to_le_bytes());
+    data.extend_from_slice(&attestation.program_hash);
+    data.extend_from_slice(&attestation.expiry.to_le_bytes());
+    data.extend_from_slice(&attestation.nonce.to_le_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new(deployment_address(program), false),
+            AccountMeta::new_readonly(authority_address(), false),
+            AccountMeta::new(*program, false),
+            AccountMeta::new(get_program_data_address(program), false),
+            AccountMeta::new(*buffer, false),
+            AccountMeta::new(used_nonce_address(attestation.nonce), false),
+            AccountMeta::new(*deployer, true),
+            AccountMeta::new_readonly(SYSVAR_INSTRUCTIONS_ID, false),
+            AccountMeta::new_readonly(SYSVAR_RENT_ID, false),
+            AccountMeta::new_readonly(SYSVAR_CLOCK_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(LOADER_ID, false),
+            AccountMeta::new_readonly(event_authority_address(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// First 8 bytes of `sha256(namespace:name)`, Anchor's discriminator scheme
+fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name));
+    hash[..8].try_into().expect("sha256 is 32 bytes")
+}
+
+fn instruction_discriminator(name: &str) -> [u8; 8] {
+    discriminator("global", name)
+}
+
+fn decode_account<T: BorshDeserialize>(name: &str, data: &[u8]) -> Result<T> {
+    let invalid = || CliError::ApiError(format!("Account is not a {}", name));
+
+    let (tag, mut body) = data.split_at_checked(8).ok_or_else(invalid)?;
+    if tag != discriminator("account", name) {
+        return Err(invalid());
+    }
+
+    // Accounts are sized for their largest layout, so trailing bytes are expected
+    T::deserialize(&mut body).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attestation() -> DeployAttestation {
+        DeployAttestation {
+            user: Pubkey::new_from_array([1; 32]),
+            action: AttestationAction::Upgrade,
+            program: Pubkey::new_from_array([5; 32]),
+            program_hash: [2; 32],
+            program_size: 1024,
+            expiry: 1_773_489_600,
+            nonce: 42,
+        }
+    }
+
+    fn read_u16(data: &[u8], at: usize) -> usize {
+        u16::from_le_bytes([data[at], data[at + 1]]) as usize
+    }
+
+    #[test]
+    fn verify_instruction_points_at_its_own_data() {
+        let signed = SignedAttestation {
+            attestation: attestation(),
+            signer: Pubkey::new_from_array([3; 32]),
+            signature: [4; 64],
+        };
+        let instruction = signed.verify_instruction();
+        let data = &instruction.data;
+
+        assert_eq!(instruction.program_id, ED25519_PROGRAM_ID);
+        assert_eq!(data[0], 1);
+        for index_at in [4, 8, 14] {
+            assert_eq!(read_u16(data, index_at), u16::MAX as usize);
+        }
+
+        let (signature_at, pubkey_at) = (read_u16(data, 2), read_u16(data, 6));
+        let (message_at, message_len) = (read_u16(data, 10), read_u16(data, 12));
+        assert_eq!(&data[signature_at..signature_at + 64], &[4; 64]);
+        assert_eq!(&data[pubkey_at..pubkey_at + 32], &[3; 32]);
+        assert_eq!(message_at + message_len, data.len());
+
+        let message = &data[message_at..];
+        assert!(message.starts_with(ATTESTATION_DOMAIN));
+        assert_eq!(
+            message.len(),
+            ATTESTATION_DOMAIN.len() + 32 + 1 + 32 + 32 + 4 + 8 + 8
+        );
+        let body = &message[ATTESTATION_DOMAIN.len()..];
+        assert_eq!(body[32], 1, "upgrade discriminator");
+        assert_eq!(&body[33..65], &[5; 32]);
+        assert_eq!(&message[message.len() - 8..], &42u64.to_le_bytes());
+    }
+
+    #[test]
+    fn upgrade_hosted_encodes_attested_params() {
+        let program = Pubkey::new_from_array([5; 32]);
+        let instruction = upgrade_hosted(
+            &program,
+            &Pubkey::new_from_array([6; 32]),
+            &Pubkey::new_from_array([7; 32]),
+            &attestation(),
+        );
+
+        let params = &instruction.data[8..];
+        assert_eq!(&params[..4], &1024u32.to_le_bytes());
+        assert_eq!(&params[4..36], &[2; 32]);
+        assert_eq!(&params[36..44], &1_773_489_600i64.to_le_bytes());
+        assert_eq!(&params[44..], &42u64.to_le_bytes());
+        assert_eq!(instruction.accounts[6].pubkey, used_nonce_address(42));
+        assert_eq!(instruction.accounts[8].pubkey, SYSVAR_INSTRUCTIONS_ID);
+    }
+
+    #[test]
+    fn write_buffer_encodes_bytes_as_a_vec() {
+        let program = Pubkey::new_from_array([5; 32]);
+        let instruction = write_buffer(
+            &program,
+            &Pubkey::new_from_array([6; 32]),
+            &Pubkey::new_from_array([7; 32]),
+            800,
+            &[9; 3],
+        );
+
+        assert_eq!(
+            &instruction.data[..8],
+            &instruction_discriminator("write_buffer")
+        );
+        assert_eq!(&instruction.data[8..], &[32, 3, 0, 0, 3, 0, 0, 0, 9, 9, 9]);
+    }
+}
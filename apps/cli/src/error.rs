@@ -18,6 +18,9 @@ pub enum CliError {
     #[error("Network error: {0}")]
     NetworkError(#[from] reqwest::Error),
 
+    #[error("Websocket error: {0}")]
+    WebSocketError(String),
+
     #[error("Credential storage error: {0}")]
     KeyringError(#[from] keyring::Error),
 
@@ -33,6 +36,12 @@ pub enum CliError {
     #[error("Authentication timeout. Please try again.")]
     AuthTimeout,
 
+    #[error("Transaction {signature} failed: {message}")]
+    TransactionFailed { signature: String, message: String },
+
+    #[error("Timed out waiting for transaction {0}")]
+    ConfirmationTimeout(String),
+
     #[error("Operation cancelled by user")]
     Cancelled,
 
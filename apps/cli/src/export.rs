@@ -0,0 +1,116 @@
+use crate::error::{CliError, Result};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Machine-readable format for list exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// One JSON object per line
+    Ndjson,
+}
+
+/// Export flags shared by list commands
+#[derive(Debug, Clone, Args)]
+pub struct ExportArgs {
+    /// Print records as CSV or NDJSON with full, untruncated fields instead of a table
+    #[arg(long, value_enum)]
+    pub export: Option<ExportFormat>,
+
+    /// Write the export to a file instead of stdout
+    #[arg(long, value_name = "FILE", requires = "export")]
+    pub out: Option<PathBuf>,
+}
+
+/// One page of a paginated list endpoint
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub has_more: bool,
+}
+
+/// Fetch pages of `page_size` starting at `offset` until the API reports no more
+///
+/// `keep_going` is checked after each page so callers can stop early, e.g.
+/// once a date-ordered listing is past the range they are interested in.
+pub async fn fetch_all<T, F, Fut>(
+    page_size: u32,
+    offset: u32,
+    mut fetch_page: F,
+    mut keep_going: impl FnMut(&[T]) -> bool,
+) -> Result<Vec<T>>
+where
+    F: FnMut(u32, u32) -> Fut,
+    Fut: Future<Output = Result<Page<T>>>,
+{
+    if page_size == 0 {
+        return Err(CliError::InvalidInput(
+            "Page size must be at least 1".to_string(),
+        ));
+    }
+
+    let mut items = Vec::new();
+    let mut offset = offset;
+
+    loop {
+        let page = fetch_page(page_size, offset).await?;
+        let fetched = page.items.len() as u32;
+        let done = !page.has_more || fetched == 0 || !keep_going(&page.items);
+
+        items.extend(page.items);
+        offset += fetched;
+
+        if done {
+            return Ok(items);
+        }
+    }
+}
+
+/// Write records as CSV or NDJSON to a file or stdout
+pub fn export_records<T: Serialize>(
+    records: &[T],
+    format: ExportFormat,
+    out: Option<&Path>,
+) -> Result<()> {
+    let writer: Box<dyn Write> =
+        match out {
+            Some(path) => Box::new(std::fs::File::create(path).map_err(|e| {
+                CliError::Other(format!("Failed to create {}: {}", path.display(), e))
+            })?),
+            None => Box::new(std::io::stdout().lock()),
+        };
+
+    let write_error = |e: &dyn std::fmt::Display| {
+        let target = out
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "stdout".to_string());
+        CliError::Other(format!("Failed to write {}: {}", target, e))
+    };
+
+    match format {
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for record in records {
+                writer.serialize(record).map_err(|e| write_error(&e))?;
+            }
+            writer.flush().map_err(|e| write_error(&e))?;
+        }
+        ExportFormat::Ndjson => {
+            let mut writer = std::io::BufWriter::new(writer);
+            for record in records {
+                serde_json::to_writer(&mut writer, record)?;
+                writer.write_all(b"\n").map_err(|e| write_error(&e))?;
+            }
+            writer.flush().map_err(|e| write_error(&e))?;
+        }
+    }
+
+    if let Some(path) = out {
+        eprintln!("Exported {} records to {}", records.len(), path.display());
+    }
+
+    Ok(())
+}
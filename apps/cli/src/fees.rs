@@ -0,0 +1,197 @@
+use crate::client::ApiClient;
+use crate::error::{CliError, Result};
+use clap::Args;
+use serde::Deserialize;
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+/// Percentiles reported by `sdt fees`
+pub const REPORTED_PERCENTILES: [u8; 6] = [25, 50, 75, 90, 95, 99];
+
+/// Priority fee options shared by commands that send transactions
+#[derive(Debug, Clone, Args)]
+pub struct PriorityFeeArgs {
+    /// Attach a compute unit price at this percentile of recent prioritization fees
+    #[arg(long, value_name = "PERCENTILE", value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub priority_fee: Option<u8>,
+
+    /// Compute unit limit to request alongside the priority fee
+    #[arg(long, requires = "priority_fee")]
+    pub compute_unit_limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PrioritizationFee {
+    slot: u64,
+    #[serde(rename = "prioritizationFee")]
+    prioritization_fee: u64,
+}
+
+/// Recent prioritization fees (micro-lamports per compute unit) for a set of accounts
+#[derive(Debug)]
+pub struct PriorityFeeEstimate {
+    /// Fees sorted ascending
+    fees: Vec<u64>,
+    pub first_slot: u64,
+    pub last_slot: u64,
+}
+
+impl PriorityFeeEstimate {
+    /// Number of slots sampled
+    pub fn samples(&self) -> usize {
+        self.fees.len()
+    }
+
+    /// Nearest-rank percentile of the sampled fees
+    pub fn percentile(&self, percentile: u8) -> u64 {
+        if self.fees.is_empty() {
+            return 0;
+        }
+
+        let rank = ((percentile as f64 / 100.0) * self.fees.len() as f64).ceil() as usize;
+        self.fees[rank.clamp(1, self.fees.len()) - 1]
+    }
+
+    pub fn min(&self) -> u64 {
+        self.fees.first().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> u64 {
+        self.fees.last().copied().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> f64 {
+        if self.fees.is_empty() {
+            return 0.0;
+        }
+
+        self.fees.iter().sum::<u64>() as f64 / self.fees.len() as f64
+    }
+}
+
+/// Fetch recent prioritization fees for transactions writing to `accounts`
+pub async fn estimate_priority_fees(
+    client: &ApiClient,
+    accounts: &[Pubkey],
+) -> Result<PriorityFeeEstimate> {
+    let params = if accounts.is_empty() {
+        None
+    } else {
+        let addresses: Vec<String> = accounts.iter().map(|a| a.to_string()).collect();
+        Some(serde_json::json!([addresses]))
+    };
+
+    let response = client
+        .send_rpc_request("getRecentPrioritizationFees", params)
+        .await?;
+
+    if let Some(error) = response.error {
+        return Err(CliError::ApiError(format!(
+            "getRecentPrioritizationFees failed: [{}] {}",
+            error.code, error.message
+        )));
+    }
+
+    let samples: Vec<PrioritizationFee> =
+        serde_json::from_value(response.result.unwrap_or_default())?;
+
+    let mut fees: Vec<u64> = samples.iter().map(|s| s.prioritization_fee).collect();
+    fees.sort_unstable();
+
+    Ok(PriorityFeeEstimate {
+        fees,
+        first_slot: samples.iter().map(|s| s.slot).min().unwrap_or_default(),
+        last_slot: samples.iter().map(|s| s.slot).max().unwrap_or_default(),
+    })
+}
+
+/// ComputeBudget instructions to prepend to a transaction
+pub fn compute_budget_instructions(
+    micro_lamports: u64,
+    compute_unit_limit: Option<u32>,
+) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+
+    if let Some(limit) = compute_unit_limit {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+    }
+    instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+        micro_lamports,
+    ));
+
+    instructions
+}
+
+impl PriorityFeeArgs {
+    /// Resolve the requested percentile into ComputeBudget instructions, if any
+    pub async fn instructions(
+        &self,
+        client: &ApiClient,
+        writable_accounts: &[Pubkey],
+    ) -> Result<Vec<Instruction>> {
+        let Some(percentile) = self.priority_fee else {
+            return Ok(Vec::new());
+        };
+
+        let estimate = estimate_priority_fees(client, writable_accounts).await?;
+        Ok(compute_budget_instructions(
+            estimate.percentile(percentile),
+            self.compute_unit_limit,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(fees: &[u64]) -> PriorityFeeEstimate {
+        PriorityFeeEstimate {
+            fees: fees.to_vec(),
+            first_slot: 0,
+            last_slot: 0,
+        }
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let estimate = estimate(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(estimate.percentile(0), 1);
+        assert_eq!(estimate.percentile(10), 1);
+        assert_eq!(estimate.percentile(50), 5);
+        assert_eq!(estimate.percentile(75), 8);
+        assert_eq!(estimate.percentile(99), 10);
+        assert_eq!(estimate.percentile(100), 10);
+    }
+
+    #[test]
+    fn percentile_of_a_single_sample_is_that_sample() {
+        let estimate = estimate(&[42]);
+        for percentile in REPORTED_PERCENTILES {
+            assert_eq!(estimate.percentile(percentile), 42);
+        }
+    }
+
+    #[test]
+    fn empty_estimate_reports_zero() {
+        let estimate = estimate(&[]);
+        assert_eq!(estimate.percentile(75), 0);
+        assert_eq!(estimate.min(), 0);
+        assert_eq!(estimate.max(), 0);
+        assert_eq!(estimate.mean(), 0.0);
+    }
+
+    #[test]
+    fn compute_budget_sets_the_limit_before_the_price() {
+        let instructions = compute_budget_instructions(5_000, Some(200_000));
+        assert_eq!(
+            instructions,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                ComputeBudgetInstruction::set_compute_unit_price(5_000),
+            ]
+        );
+        assert_eq!(compute_budget_instructions(5_000, None).len(), 1);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::address::{default_keypair_path, read_keypair_bytes};
+use crate::config::Config;
+use crate::error::{CliError, Result};
+use solana_derivation_path::DerivationPath;
+use solana_keypair::seed_derivable::keypair_from_seed_and_derivation_path;
+use solana_keypair::{keypair_from_seed, Keypair};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const APP_NAME: &str = "solana-dev-tools";
+const KEYS_DIR: &str = "keys";
+const MAX_NAME_LENGTH: usize = 64;
+
+/// BIP44 path used by Phantom, Solflare and `solana-keygen --derivation-path`
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+/// A keypair stored in the key directory
+#[derive(Debug, Clone)]
+pub struct StoredKey {
+    pub name: String,
+    pub path: PathBuf,
+    pub pubkey: Pubkey,
+}
+
+/// Named keypairs stored as Solana CLI JSON files under the config directory
+pub struct KeyStore {
+    dir: PathBuf,
+}
+
+impl KeyStore {
+    /// Open the key directory in the user's config directory
+    pub fn open() -> Result<Self> {
+        let dir = dirs::config_dir()
+            .map(|dir| dir.join(APP_NAME).join(KEYS_DIR))
+            .ok_or_else(|| {
+                CliError::ConfigError("Could not determine config directory".to_string())
+            })?;
+        Ok(Self { dir })
+    }
+
+    /// Get the key directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the keypair file for `name`
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+
+    /// Whether a key with this name exists
+    pub fn contains(&self, name: &str) -> bool {
+        validate_name(name).is_ok() && self.path(name).is_file()
+    }
+
+    /// Load a stored key by name
+    pub fn get(&self, name: &str) -> Result<StoredKey> {
+        validate_name(name)?;
+        let path = self.path(name);
+        if !path.is_file() {
+            return Err(CliError::InvalidInput(format!(
+                "No key named '{}'. Run 'sdt keys list' to see stored keys",
+                name
+            )));
+        }
+
+        let keypair = read_keypair(&path)?;
+        Ok(StoredKey {
+            name: name.to_string(),
+            path,
+            pubkey: keypair.pubkey(),
+        })
+    }
+
+    /// All stored keys, sorted by name (unreadable files are skipped)
+    pub fn list(&self) -> Result<Vec<StoredKey>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = std::fs::read_dir(&self.dir).map_err(|e| key_error(&self.dir, e))?;
+
+        let mut keys: Vec<StoredKey> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_string();
+                let keypair = read_keypair(&path).ok()?;
+                Some(StoredKey {
+                    name,
+                    pubkey: keypair.pubkey(),
+                    path,
+                })
+            })
+            .collect();
+
+        keys.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(keys)
+    }
+
+    /// Write a keypair, readable only by the current user
+    pub fn save(&self, name: &str, keypair: &Keypair, overwrite: bool) -> Result<StoredKey> {
+        validate_name(name)?;
+        let path = self.path(name);
+
+        if path.exists() && !overwrite {
+            return Err(CliError::InvalidInput(format!(
+                "A key named '{}' already exists. Pass --force to overwrite it",
+                name
+            )));
+        }
+
+        create_private_dir(&self.dir)?;
+        write_keypair(&path, keypair)?;
+
+        Ok(StoredKey {
+            name: name.to_string(),
+            path,
+            pubkey: keypair.pubkey(),
+        })
+    }
+}
+
+/// Write a Solana CLI keypair file, readable only by the current user
+pub fn write_keypair(path: &Path, keypair: &Keypair) -> Result<()> {
+    // Write a fresh file and move it into place, so an existing file with
+    // looser permissions never holds the secret
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| CliError::InvalidInput(format!("Invalid key path: {}", path.display())))?;
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+    // Left over from an interrupted write
+    let _ = std::fs::remove_file(&temp_path);
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let json = serde_json::to_string(&keypair.to_bytes().to_vec())?;
+    let result = options
+        .open(&temp_path)
+        .and_then(|mut file| file.write_all(json.as_bytes()))
+        .and_then(|()| std::fs::rename(&temp_path, path));
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(key_error(path, e));
+    }
+
+    Ok(())
+}
+
+/// Resolve a keypair reference that may be a file path or a stored key name
+pub fn resolve_keypair_path(reference: &str) -> Result<PathBuf> {
+    let path = crate::address::expand_tilde(reference);
+    if path.is_file() {
+        return Ok(path);
+    }
+
+    let store = KeyStore::open()?;
+    if store.contains(reference) {
+        return Ok(store.path(reference));
+    }
+
+    Ok(path)
+}
+
+/// Load the configured default signer (wallet.keypair or the Solana CLI default)
+pub fn default_signer(config: &Config) -> Result<Keypair> {
+    let path = default_keypair_path(config)?;
+    if !path.is_file() {
+        return Err(CliError::InvalidInput(format!(
+            "No signer found at {}. Create one with 'sdt keys new <name> --default'",
+            path.display()
+        )));
+    }
+    read_keypair(&path)
+}
+
+/// Read a Solana CLI keypair file, checking the public key matches the secret
+pub fn read_keypair(path: &Path) -> Result<Keypair> {
+    let bytes = read_keypair_bytes(path)?;
+    let secret: [u8; 32] = bytes[..32].try_into().expect("keypair is 64 bytes");
+    let keypair = Keypair::new_from_array(secret);
+
+    if keypair.pubkey().to_bytes()[..] != bytes[32..] {
+        return Err(CliError::InvalidInput(format!(
+            "{} is corrupt: its public key does not match the secret key",
+            path.display()
+        )));
+    }
+
+    Ok(keypair)
+}
+
+/// Derive a keypair from a BIP39 seed
+///
+/// Without a derivation path the first 32 bytes of the seed are used, matching
+/// `solana-keygen`'s default.
+pub fn keypair_from_bip39_seed(seed: &[u8], derivation_path: Option<&str>) -> Result<Keypair> {
+    let result = match derivation_path {
+        Some(path) => {
+            keypair_from_seed_and_derivation_path(seed, Some(parse_derivation_path(path)?))
+        }
+        None => keypair_from_seed(seed),
+    };
+
+    result.map_err(|e| CliError::Other(format!("Failed to derive keypair: {}", e)))
+}
+
+/// Parse `m/44'/501'/0'/0'` or the `solana-keygen` shorthand `<account>/<change>`
+pub fn parse_derivation_path(path: &str) -> Result<DerivationPath> {
+    let parsed = if path.starts_with('m') {
+        DerivationPath::from_absolute_path_str(path)
+    } else {
+        DerivationPath::from_key_str(path)
+    };
+
+    parsed.map_err(|e| CliError::InvalidInput(format!("Invalid derivation path {}: {}", path, e)))
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(CliError::InvalidInput(format!(
+            "Invalid key name '{}': use up to {} letters, digits, '-' or '_'",
+            name, MAX_NAME_LENGTH
+        )))
+    }
+}
+
+fn create_private_dir(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).map_err(|e| key_error(dir, e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))
+            .map_err(|e| key_error(dir, e))?;
+    }
+
+    Ok(())
+}
+
+fn key_error(path: &Path, error: std::io::Error) -> CliError {
+    CliError::Other(format!(
+        "Key storage error at {}: {}",
+        path.display(),
+        error
+    ))
+}
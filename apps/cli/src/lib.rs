@@ -1,9 +1,18 @@
+pub mod address;
+pub mod amount;
 pub mod auth;
 pub mod cli;
 pub mod client;
+pub mod cluster;
 pub mod commands;
 pub mod config;
+pub mod deployer;
 pub mod error;
+pub mod export;
+pub mod fees;
+pub mod keys;
+pub mod quota;
+pub mod transaction;
 
 pub use cli::Cli;
 pub use error::{CliError, Result};
@@ -1,7 +1,11 @@
 use clap::Parser;
 use solana_dev_tools::{
     cli::{Cli, Commands},
-    commands::{handle_apikey_command, handle_auth_command, handle_airdrop_command, handle_config_command, handle_rpc_command, handle_deploy_command},
+    commands::{
+        handle_airdrop_command, handle_apikey_command, handle_auth_command, handle_cache_command,
+        handle_config_command, handle_deploy_command, handle_fees_command, handle_keys_command,
+        handle_logs_command, handle_programs_command, handle_rpc_command, handle_wallet_command,
+    },
     config::manager::ConfigManager,
     error::Result,
 };
@@ -35,6 +39,12 @@ async fn run() -> Result<()> {
     // Determine API URL (CLI flag > env var > config file)
     let api_url = cli.api_url.unwrap_or(config.api.base_url);
 
+    // Determine target cluster (CLI flag > env var > config file)
+    let cluster = cli.cluster.unwrap_or(config.network.cluster);
+
+    // Determine output format (CLI flag > config file)
+    let output = cli.output.unwrap_or(config.output.format);
+
     // Handle color output
     if cli.no_color {
         colored::control::set_override(false);
@@ -46,19 +56,40 @@ async fn run() -> Result<()> {
             handle_auth_command(command, &api_url).await?;
         }
         Commands::Airdrop { command } => {
-            handle_airdrop_command(command, &api_url).await?;
+            handle_airdrop_command(command, &api_url, cluster).await?;
         }
         Commands::ApiKey { command } => {
             handle_apikey_command(command, &api_url).await?;
         }
+        Commands::Keys { command } => {
+            handle_keys_command(command).await?;
+        }
         Commands::Config { command } => {
             handle_config_command(command).await?;
         }
+        Commands::Cache { command } => {
+            handle_cache_command(command).await?;
+        }
         Commands::Rpc { command } => {
-            handle_rpc_command(command, &api_url).await?;
+            handle_rpc_command(command, &api_url, cluster).await?;
         }
         Commands::Deploy { command } => {
-            handle_deploy_command(command, &api_url).await?;
+            handle_deploy_command(command, &api_url, cluster).await?;
+        }
+        Commands::Programs { command } => {
+            handle_programs_command(command, &api_url, cluster).await?;
+        }
+        Commands::Wallet { command } => {
+            handle_wallet_command(command, &api_url, cluster, output).await?;
+        }
+        Commands::Fees {
+            accounts,
+            percentile,
+        } => {
+            handle_fees_command(accounts, percentile, &api_url, cluster).await?;
+        }
+        Commands::Logs(args) => {
+            handle_logs_command(args, &api_url, cluster).await?;
         }
     }
 
@@ -0,0 +1,324 @@
+use crate::amount::Lamports;
+use crate::client::api::UsageStatsData;
+use crate::client::{ApiClient, RpcCache};
+use crate::cluster::Cluster;
+use crate::config::Config;
+use crate::error::Result;
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Response cache key the quota is stored under
+const QUOTA_CACHE_KEY: &str = "airdrop/quota";
+
+/// How long a fetched quota is trusted before asking the API again
+const QUOTA_TTL_SECONDS: i64 = 60;
+
+/// The user's airdrop limits and usage for the current UTC day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirdropQuota {
+    fetched_at: i64,
+    max_per_request: u64,
+    daily_requests: u32,
+    daily_volume: u64,
+    requests_today: u32,
+    volume_today: u64,
+    /// Where the quota is cached; `None` if caching is disabled or the cache
+    /// directory is unavailable
+    #[serde(skip)]
+    store: Option<QuotaStore>,
+}
+
+/// Location of a quota in the response cache
+#[derive(Debug, Clone)]
+struct QuotaStore {
+    cache: RpcCache,
+    params: serde_json::Value,
+}
+
+/// Why an airdrop amount does not fit the remaining quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaExceeded {
+    /// No requests left today
+    Requests,
+    /// More than a single request may carry
+    PerRequest,
+    /// More than the volume left today
+    Volume,
+}
+
+impl AirdropQuota {
+    /// Load the quota from the response cache, refreshing it from the API when stale
+    ///
+    /// The response cache is only used when `cache.enabled` is set; otherwise
+    /// the quota is fetched fresh. Returns `None` if the API does not report limits.
+    pub async fn load(
+        client: &ApiClient,
+        config: &Config,
+        cluster: Cluster,
+        user_id: &str,
+        api_url: &str,
+    ) -> Result<Option<Self>> {
+        let cache = if config.cache.enabled {
+            RpcCache::open(Duration::from_secs(QUOTA_TTL_SECONDS as u64), cluster)
+                .map_err(|e| tracing::debug!("Not caching airdrop quota: {}", e))
+                .ok()
+        } else {
+            None
+        };
+        let store = cache.map(|cache| QuotaStore {
+            cache,
+            params: serde_json::json!({ "userId": user_id, "apiUrl": api_url }),
+        });
+
+        let cached = store.as_ref().and_then(|store| {
+            let value = store
+                .cache
+                .get_stored(QUOTA_CACHE_KEY, Some(&store.params))?;
+            serde_json::from_value::<Self>(value).ok()
+        });
+        if let Some(mut cached) = cached {
+            cached.store = store;
+            return Ok(Some(cached));
+        }
+
+        let usage = client.get_airdrop_usage().await?.usage;
+        let Some(mut quota) = Self::from_usage(&usage) else {
+            return Ok(None);
+        };
+        quota.store = store;
+        quota.save();
+
+        Ok(Some(quota))
+    }
+
+    /// Build an uncached quota from a usage response, if it reports limits
+    pub fn from_usage(usage: &UsageStatsData) -> Option<Self> {
+        let limits = usage.limits.as_ref()?;
+
+        Some(Self {
+            fetched_at: Utc::now().timestamp(),
+            max_per_request: limits.max_per_request.0,
+            daily_requests: limits.daily_requests,
+            daily_volume: limits.daily_volume.0,
+            requests_today: usage.today.requests,
+            volume_today: usage.today.amount.0,
+            store: None,
+        })
+    }
+
+    /// When a fetched quota stops being trusted: after the TTL, or when the
+    /// daily window rolls over at midnight UTC, whichever comes first
+    fn expires_at(&self) -> i64 {
+        let Some(fetched_at) = DateTime::from_timestamp(self.fetched_at, 0) else {
+            return self.fetched_at;
+        };
+
+        let reset = fetched_at + time_until_reset_from(fetched_at);
+        (self.fetched_at + QUOTA_TTL_SECONDS).min(reset.timestamp())
+    }
+
+    pub fn max_per_request(&self) -> Lamports {
+        Lamports(self.max_per_request)
+    }
+
+    pub fn daily_requests(&self) -> u32 {
+        self.daily_requests
+    }
+
+    pub fn daily_volume(&self) -> Lamports {
+        Lamports(self.daily_volume)
+    }
+
+    pub fn remaining_requests(&self) -> u32 {
+        self.daily_requests.saturating_sub(self.requests_today)
+    }
+
+    pub fn remaining_volume(&self) -> Lamports {
+        Lamports(self.daily_volume.saturating_sub(self.volume_today))
+    }
+
+    /// Largest amount a single request may ask for right now
+    pub fn max_allowed(&self) -> Lamports {
+        if self.remaining_requests() == 0 {
+            return Lamports::ZERO;
+        }
+        self.max_per_request().min(self.remaining_volume())
+    }
+
+    /// Check whether `amount` can be requested now
+    pub fn check(&self, amount: Lamports) -> std::result::Result<(), QuotaExceeded> {
+        if self.remaining_requests() == 0 {
+            Err(QuotaExceeded::Requests)
+        } else if amount > self.max_per_request() {
+            Err(QuotaExceeded::PerRequest)
+        } else if amount > self.remaining_volume() {
+            Err(QuotaExceeded::Volume)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Human-readable explanation of why `amount` was refused
+    pub fn describe(&self, amount: Lamports, exceeded: QuotaExceeded) -> String {
+        match exceeded {
+            QuotaExceeded::Requests => format!(
+                "Requesting {}, but all {} airdrop requests for today are used, resets in {}",
+                amount,
+                self.daily_requests,
+                format_duration(time_until_reset())
+            ),
+            QuotaExceeded::PerRequest => format!(
+                "Requesting {}, but the maximum per request is {}",
+                amount,
+                self.max_per_request()
+            ),
+            QuotaExceeded::Volume => format!(
+                "Requesting {}, {} remaining today, resets in {}",
+                amount,
+                self.remaining_volume(),
+                format_duration(time_until_reset())
+            ),
+        }
+    }
+
+    /// Count a successful airdrop against the cached quota
+    pub fn record(&mut self, amount: Lamports) {
+        self.requests_today = self.requests_today.saturating_add(1);
+        self.volume_today = self.volume_today.saturating_add(amount.0);
+        self.save();
+    }
+
+    /// Drop the cached quota, e.g. after the API rejected a request
+    pub fn invalidate(&self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        if let Err(e) = store.cache.remove(QUOTA_CACHE_KEY, Some(&store.params)) {
+            tracing::debug!("Failed to drop cached airdrop quota: {}", e);
+        }
+    }
+
+    /// Persist the quota (best effort)
+    fn save(&self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        let result = serde_json::to_value(self)
+            .map_err(Into::into)
+            .and_then(|value| {
+                store.cache.put_until(
+                    QUOTA_CACHE_KEY,
+                    Some(&store.params),
+                    &value,
+                    self.expires_at().max(0) as u64,
+                )
+            });
+
+        if let Err(e) = result {
+            tracing::debug!("Failed to cache airdrop quota: {}", e);
+        }
+    }
+}
+
+/// Time until daily airdrop limits reset at midnight UTC
+pub fn time_until_reset() -> Duration {
+    time_until_reset_from(Utc::now())
+}
+
+fn time_until_reset_from(now: DateTime<Utc>) -> Duration {
+    let today = now.date_naive();
+    let midnight = today
+        .succ_opt()
+        .unwrap_or(today)
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    (midnight - now).to_std().unwrap_or_default()
+}
+
+/// Format a duration as e.g. `4h12m`
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    format!("{}h{:02}m", minutes / 60, minutes % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::LAMPORTS_PER_SOL;
+
+    // 2026-03-14 12:00:00 UTC
+    const NOON: i64 = 1_773_489_600;
+
+    fn quota(requests_today: u32, volume_today: u64) -> AirdropQuota {
+        AirdropQuota {
+            fetched_at: NOON,
+            max_per_request: LAMPORTS_PER_SOL,
+            daily_requests: 5,
+            daily_volume: 3 * LAMPORTS_PER_SOL,
+            requests_today,
+            volume_today,
+            store: None,
+        }
+    }
+
+    #[test]
+    fn cached_quota_expires_after_the_ttl() {
+        assert_eq!(quota(0, 0).expires_at(), NOON + QUOTA_TTL_SECONDS);
+    }
+
+    #[test]
+    fn cached_quota_expires_when_the_day_rolls_over() {
+        let midnight = NOON + 12 * 3600;
+        let quota = AirdropQuota {
+            fetched_at: midnight - 10,
+            ..quota(5, 3 * LAMPORTS_PER_SOL)
+        };
+        assert_eq!(quota.expires_at(), midnight);
+    }
+
+    #[test]
+    fn cached_quota_round_trips_without_its_location() {
+        let json = serde_json::to_value(quota(2, LAMPORTS_PER_SOL)).unwrap();
+        let quota: AirdropQuota = serde_json::from_value(json).unwrap();
+        assert_eq!(quota.remaining_requests(), 3);
+        assert_eq!(quota.remaining_volume(), Lamports(2 * LAMPORTS_PER_SOL));
+        assert!(quota.store.is_none());
+    }
+
+    #[test]
+    fn check_reports_the_first_limit_hit() {
+        assert_eq!(quota(5, 0).check(Lamports(1)), Err(QuotaExceeded::Requests));
+        assert_eq!(
+            quota(0, 0).check(Lamports(LAMPORTS_PER_SOL + 1)),
+            Err(QuotaExceeded::PerRequest)
+        );
+        assert_eq!(
+            quota(1, 5 * LAMPORTS_PER_SOL / 2).check(Lamports(LAMPORTS_PER_SOL)),
+            Err(QuotaExceeded::Volume)
+        );
+        assert_eq!(quota(1, 0).check(Lamports(LAMPORTS_PER_SOL)), Ok(()));
+    }
+
+    #[test]
+    fn max_allowed_is_capped_by_request_and_remaining_volume() {
+        assert_eq!(quota(0, 0).max_allowed(), Lamports(LAMPORTS_PER_SOL));
+        assert_eq!(
+            quota(1, 5 * LAMPORTS_PER_SOL / 2).max_allowed(),
+            Lamports(LAMPORTS_PER_SOL / 2)
+        );
+        assert_eq!(quota(5, 0).max_allowed(), Lamports::ZERO);
+    }
+
+    #[test]
+    fn window_resets_at_midnight_utc() {
+        // 19:48:30 UTC
+        let now = DateTime::from_timestamp(NOON + 7 * 3600 + 48 * 60 + 30, 0).unwrap();
+        let remaining = time_until_reset_from(now);
+        assert_eq!(remaining, Duration::from_secs(4 * 3600 + 11 * 60 + 30));
+        assert_eq!(format_duration(remaining), "4h11m");
+    }
+}
@@ -0,0 +1,84 @@
+use crate::client::api::{Commitment, SignatureStatus};
+use crate::client::ApiClient;
+use crate::error::{CliError, Result};
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use std::time::Duration;
+
+/// Sign `instructions` with `signers`, the first of which pays the fee, and
+/// submit them
+///
+/// `compute_budget` comes from [`PriorityFeeArgs::instructions`] and is
+/// prepended so every transaction a command sends carries the same priority fee.
+///
+/// [`PriorityFeeArgs::instructions`]: crate::fees::PriorityFeeArgs::instructions
+pub async fn sign_and_send(
+    client: &ApiClient,
+    compute_budget: &[Instruction],
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<String> {
+    let payer = signers
+        .first()
+        .ok_or_else(|| CliError::Other("A transaction needs a fee payer".to_string()))?
+        .pubkey();
+    let instructions: Vec<Instruction> =
+        compute_budget.iter().chain(instructions).cloned().collect();
+
+    let blockhash = client.get_latest_blockhash().await?;
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), signers, blockhash);
+
+    client.send_transaction(&transaction).await
+}
+
+/// Poll the transaction status until it reaches `commitment`, fails, or times out
+pub async fn wait_for_confirmation(
+    client: &ApiClient,
+    signature: &str,
+    commitment: Commitment,
+    timeout: Duration,
+) -> Result<SignatureStatus> {
+    let spinner = indicatif::ProgressBar::new_spinner();
+    spinner.set_message(format!("Waiting for {} confirmation...", commitment));
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    let poll_interval = Duration::from_secs(1);
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    loop {
+        if let Some(status) = client.get_signature_status(signature).await? {
+            if let Some(err) = status.err {
+                spinner.finish_and_clear();
+                return Err(CliError::TransactionFailed {
+                    signature: signature.to_string(),
+                    message: err.to_string(),
+                });
+            }
+
+            match status.confirmation_status {
+                Some(reached) if reached >= commitment => {
+                    spinner.finish_and_clear();
+                    return Ok(status);
+                }
+                Some(reached) => spinner.set_message(format!(
+                    "Transaction {} in slot {}, waiting for {}...",
+                    reached, status.slot, commitment
+                )),
+                None => spinner.set_message(format!(
+                    "Transaction seen in slot {}, waiting for {}...",
+                    status.slot, commitment
+                )),
+            }
+        }
+
+        if tokio::time::Instant::now() + poll_interval > deadline {
+            spinner.finish_and_clear();
+            return Err(CliError::ConfirmationTimeout(signature.to_string()));
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
@@ -0,0 +1,110 @@
+//! Verification of API deploy attestations signed with ed25519
+//!
+//! The API signs a [`DeployAttestation`] and the client places an
+//! Ed25519Program instruction verifying that signature directly before
+//! `register_deployment` or `upgrade_hosted`. The runtime fails the
+//! transaction if the signature is invalid, so here we only check that the
+//! instruction exists and covers the expected key and message.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::constants::ATTESTATION_DOMAIN;
+use crate::error::ErrorCode;
+
+/// Size of the Ed25519Program instruction header: signature count and padding
+const HEADER_LEN: usize = 2;
+/// Size of one `Ed25519SignatureOffsets` entry
+const OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+/// Instruction index meaning "this instruction's own data"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// The instruction an attestation is for, so one can't be replayed against the other
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttestationAction {
+    Register,
+    Upgrade,
+}
+
+/// What the API approves for a single deployment
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DeployAttestation {
+    pub user: Pubkey,
+    pub action: AttestationAction,
+    /// Program being registered or upgraded
+    pub program: Pubkey,
+    /// SHA-256 of the program bytes
+    pub program_hash: [u8; 32],
+    pub program_size: u32,
+    /// Unix timestamp after which the attestation is no longer accepted
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+impl DeployAttestation {
+    /// Bytes the API signs: the domain prefix followed by the Borsh encoding
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = ATTESTATION_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+/// Check the instruction before the current one verifies `attestation`
+/// signed by `signer`
+pub fn verify(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    attestation: &DeployAttestation,
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, ErrorCode::MissingAttestation);
+
+    let instruction = load_instruction_at_checked(current as usize - 1, instructions)?;
+    require_keys_eq!(
+        instruction.program_id,
+        ed25519_program::ID,
+        ErrorCode::MissingAttestation
+    );
+
+    let data = instruction.data.as_slice();
+    require!(
+        data.len() >= HEADER_LEN + OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidAttestation
+    );
+
+    let offsets = &data[HEADER_LEN..HEADER_LEN + OFFSETS_LEN];
+    let field = |index: usize| u16::from_le_bytes([offsets[index * 2], offsets[index * 2 + 1]]);
+    let [_, signature_ix, pubkey_offset, pubkey_ix, message_offset, message_len, message_ix] =
+        [0, 1, 2, 3, 4, 5, 6].map(field);
+
+    // Everything must live in the Ed25519 instruction itself, otherwise the
+    // signature could cover data other than what we read here
+    require!(
+        [signature_ix, pubkey_ix, message_ix]
+            .iter()
+            .all(|&index| index == CURRENT_INSTRUCTION),
+        ErrorCode::InvalidAttestation
+    );
+
+    let pubkey = slice(data, pubkey_offset, PUBKEY_LEN as u16)?;
+    require!(pubkey == signer.as_ref(), ErrorCode::InvalidAttestation);
+
+    let message = slice(data, message_offset, message_len)?;
+    require!(
+        message == attestation.message()?.as_slice(),
+        ErrorCode::InvalidAttestation
+    );
+
+    Ok(())
+}
+
+fn slice(data: &[u8], offset: u16, len: u16) -> Result<&[u8]> {
+    let start = offset as usize;
+    data.get(start..start + len as usize)
+        .ok_or_else(|| error!(ErrorCode::InvalidAttestation))
+}
@@ -1,4 +1,42 @@
 use anchor_lang::prelude::*;
 
+/// Seed for the global `DeployerConfig` PDA
 #[constant]
-pub const SEED: &str = "anchor";
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Seed for the treasury PDA that sponsors rent and collects fees
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+/// Default time a hosted program stays deployed before it can be reclaimed
+#[constant]
+pub const DEFAULT_HOSTING_DURATION: i64 = 7 * 24 * 60 * 60;
+
+/// Extensions are bought in whole days
+#[constant]
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Largest program the upgradeable loader accepts
+#[constant]
+pub const MAX_PERMITTED_PROGRAM_SIZE: u32 = 10 * 1024 * 1024;
+
+/// Seed for a `HostedDeployment` PDA, followed by the program address
+#[constant]
+pub const DEPLOYMENT_SEED: &[u8] = b"deployment";
+
+/// Seed for the PDA that holds buffer and upgrade authority over hosted programs
+#[constant]
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+
+/// Seed for a `UserQuota` PDA, followed by the user's wallet
+#[constant]
+pub const USER_QUOTA_SEED: &[u8] = b"quota";
+
+/// Seed for a `UsedNonce` PDA, followed by the little-endian nonce
+#[constant]
+pub const NONCE_SEED: &[u8] = b"nonce";
+
+/// Prefix of attestation messages, so API signatures can't be replayed as
+/// anything else signed with the same key
+#[constant]
+pub const ATTESTATION_DOMAIN: &[u8] = b"program-deployer:attestation:v1";
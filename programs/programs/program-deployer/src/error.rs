@@ -2,6 +2,71 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum ErrorCode {
-    #[msg("Custom error message")]
-    CustomError,
+    // Configuration
+    #[msg("Signer is not the deployer admin")]
+    Unauthorized,
+    #[msg("Hosting duration must be positive")]
+    InvalidHostingDuration,
+    #[msg("Max program size must be between 1 byte and the loader's 10 MiB limit")]
+    InvalidMaxProgramSize,
+    #[msg("Per-user quotas must be non-zero")]
+    InvalidQuota,
+    #[msg("Max hosting period must be at least the hosting duration")]
+    InvalidMaxHostingPeriod,
+    #[msg("New deployments are paused")]
+    Paused,
+
+    // Registration limits
+    #[msg("Program size must be non-zero")]
+    EmptyProgram,
+    #[msg("Program exceeds the maximum hosted program size")]
+    ProgramTooLarge,
+    #[msg("Upgrade is larger than the space allocated for the hosted program")]
+    UpgradeTooLarge,
+    #[msg("Wallet already has the maximum number of hosted deployments")]
+    TooManyDeployments,
+    #[msg("Deployment exceeds the wallet's hosted bytes quota")]
+    HostedBytesExceeded,
+    #[msg("Deployment exceeds the rent the treasury may sponsor for this wallet in the current window")]
+    SponsoredLamportsExceeded,
+
+    // Attestation
+    #[msg("Expected an Ed25519Program instruction attesting the deployment before this one")]
+    MissingAttestation,
+    #[msg("Attestation is not signed by the API key or does not match the request")]
+    InvalidAttestation,
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+    #[msg("Buffer contents do not match the attested program hash")]
+    ProgramHashMismatch,
+
+    // Deployment lifecycle
+    #[msg("Signer is not the wallet that registered this deployment")]
+    UnauthorizedDeployer,
+    #[msg("Signer is not the wallet that requested this deployment")]
+    UnauthorizedClaimant,
+    #[msg("Deployment has already been deployed")]
+    AlreadyDeployed,
+    #[msg("Program has not been deployed yet")]
+    NotDeployed,
+    #[msg("Program has already been claimed")]
+    AlreadyClaimed,
+    #[msg("Deployment has already been reclaimed")]
+    AlreadyReclaimed,
+    #[msg("Hosting period has expired")]
+    Expired,
+    #[msg("Registration expired before the program was deployed")]
+    DeploymentExpired,
+    #[msg("Hosting period has not expired yet")]
+    NotExpired,
+    #[msg("Extension must be at least one day")]
+    InvalidExtension,
+    #[msg("Extension would exceed the maximum hosting period")]
+    HostingPeriodExceeded,
+    #[msg("Extension fees are not accepted in tokens")]
+    TokenFeesDisabled,
+    #[msg("Token fee accounts must all be given, for the configured fee mint and the treasury")]
+    InvalidFeeAccounts,
+    #[msg("Expected [deployment, user quota, program, programdata or buffer] for each deployment to reclaim")]
+    InvalidReclaimAccounts,
 }
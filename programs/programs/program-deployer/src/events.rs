@@ -0,0 +1,61 @@
+//! Events emitted through `emit_cpi!` so indexers can follow deployments from
+//! transaction data rather than polling accounts
+
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct DeploymentRegistered {
+    pub deployment: Pubkey,
+    pub deployer: Pubkey,
+    pub program: Pubkey,
+    pub program_size: u32,
+    pub program_hash: [u8; 32],
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct ProgramDeployed {
+    pub deployment: Pubkey,
+    pub program: Pubkey,
+    pub deploy_slot: u64,
+    pub expires_at: i64,
+    pub rent_sponsored: u64,
+}
+
+#[event]
+pub struct AuthorityClaimed {
+    pub deployment: Pubkey,
+    pub program: Pubkey,
+    pub claimant: Pubkey,
+    /// Rent reimbursement plus claim fee paid to the treasury
+    pub amount_paid: u64,
+}
+
+#[event]
+pub struct HostingExtended {
+    pub deployment: Pubkey,
+    pub program: Pubkey,
+    pub expires_at: i64,
+    pub fee: u64,
+    /// Token the fee was paid in, `None` for SOL
+    pub fee_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct HostedProgramUpgraded {
+    pub deployment: Pubkey,
+    pub program: Pubkey,
+    pub upgrade_count: u32,
+    pub slot: u64,
+    pub program_size: u32,
+    pub buffer_hash: [u8; 32],
+}
+
+#[event]
+pub struct DeploymentReclaimed {
+    pub deployment: Pubkey,
+    pub program: Pubkey,
+    pub cranker: Pubkey,
+    /// Lamports returned to the treasury
+    pub lamports_reclaimed: u64,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use solana_loader_v3_interface::get_program_data_address;
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::constants::{
+    AUTHORITY_SEED, CONFIG_SEED, DEPLOYMENT_SEED, TREASURY_SEED, USER_QUOTA_SEED,
+};
+use crate::error::ErrorCode;
+use crate::events::AuthorityClaimed;
+use crate::loader;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment, UserQuota};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimAuthority<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump, has_one = treasury)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(
+        mut,
+        seeds = [DEPLOYMENT_SEED, hosted_program.key().as_ref()],
+        bump = deployment.bump,
+        has_one = deployer @ ErrorCode::UnauthorizedClaimant
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    #[account(
+        mut,
+        seeds = [USER_QUOTA_SEED, deployer.key().as_ref()],
+        bump = user_quota.bump
+    )]
+    pub user_quota: Account<'info, UserQuota>,
+
+    #[account(mut, seeds = [TREASURY_SEED], bump = config.treasury_bump)]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: PDA that is the program's current upgrade authority
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: the hosted program, bound to the deployment by its seeds
+    pub hosted_program: UncheckedAccount<'info>,
+
+    /// CHECK: the program's programdata account
+    #[account(
+        mut,
+        address = get_program_data_address(&hosted_program.key())
+    )]
+    pub programdata: UncheckedAccount<'info>,
+
+    /// Becomes the upgrade authority and pays the rent reimbursement and fee
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub loader: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimAuthority>) -> Result<()> {
+    let deployment = &ctx.accounts.deployment;
+
+    match deployment.status {
+        DeploymentStatus::Hosted => {}
+        DeploymentStatus::Registered => return err!(ErrorCode::NotDeployed),
+        DeploymentStatus::Claimed => return err!(ErrorCode::AlreadyClaimed),
+        DeploymentStatus::Reclaimed => return err!(ErrorCode::Expired),
+    }
+    require!(
+        !deployment.is_expired(Clock::get()?.unix_timestamp),
+        ErrorCode::Expired
+    );
+
+    let amount = deployment
+        .rent_sponsored
+        .checked_add(ctx.accounts.config.claim_fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.deployer.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    loader::set_upgrade_authority(
+        &ctx.accounts.hosted_program.to_account_info(),
+        &ctx.accounts.programdata.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.deployer.to_account_info(),
+        &ctx.accounts.loader.to_account_info(),
+        &[&[AUTHORITY_SEED, &[ctx.bumps.authority]]],
+    )?;
+
+    let deployment = &mut ctx.accounts.deployment;
+    deployment.status = DeploymentStatus::Claimed;
+    ctx.accounts.user_quota.release(deployment.program_size);
+
+    emit_cpi!(AuthorityClaimed {
+        deployment: deployment.key(),
+        program: deployment.program,
+        claimant: deployment.deployer,
+        amount_paid: amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use solana_loader_v3_interface::{get_program_data_address, state::UpgradeableLoaderState};
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::constants::{AUTHORITY_SEED, CONFIG_SEED, DEPLOYMENT_SEED, TREASURY_SEED};
+use crate::error::ErrorCode;
+use crate::events::ProgramDeployed;
+use crate::loader;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeployProgram<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump, has_one = treasury)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(
+        mut,
+        seeds = [DEPLOYMENT_SEED, hosted_program.key().as_ref()],
+        bump = deployment.bump,
+        has_one = deployer @ ErrorCode::UnauthorizedDeployer,
+        has_one = buffer,
+        constraint = deployment.status == DeploymentStatus::Registered @ ErrorCode::AlreadyDeployed
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    #[account(mut, seeds = [TREASURY_SEED], bump = config.treasury_bump)]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: PDA that becomes the program's upgrade authority
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// New program account, created here
+    #[account(mut)]
+    pub hosted_program: Signer<'info>,
+
+    /// CHECK: the program's programdata address, created by the loader
+    #[account(
+        mut,
+        address = get_program_data_address(&hosted_program.key())
+    )]
+    pub programdata: UncheckedAccount<'info>,
+
+    /// CHECK: the deployment's buffer, validated by `has_one` and the loader
+    #[account(mut)]
+    pub buffer: UncheckedAccount<'info>,
+
+    /// Pays for the program account, the one rent the treasury couldn't reclaim
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub loader: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<DeployProgram>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.paused, ErrorCode::Paused);
+
+    // Deploying resets the hosting period, so a lapsed registration must not
+    // get a fresh one; it can only be reclaimed
+    require!(
+        !ctx.accounts
+            .deployment
+            .is_expired(ctx.accounts.clock.unix_timestamp),
+        ErrorCode::DeploymentExpired
+    );
+
+    let program_len = ctx.accounts.deployment.program_size as usize;
+
+    // Only deploy the bytes the API attested
+    let buffer_hash = {
+        let data = ctx.accounts.buffer.try_borrow_data()?;
+        let program_bytes = data
+            .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+            .and_then(|bytes| bytes.get(..program_len))
+            .ok_or(ErrorCode::ProgramHashMismatch)?;
+        hash(program_bytes).to_bytes()
+    };
+    require!(
+        buffer_hash == ctx.accounts.deployment.program_hash,
+        ErrorCode::ProgramHashMismatch
+    );
+
+    let rent = &ctx.accounts.rent;
+    let program_lamports = rent.minimum_balance(UpgradeableLoaderState::size_of_program());
+    let programdata_lamports =
+        rent.minimum_balance(UpgradeableLoaderState::size_of_programdata(program_len));
+
+    loader::deploy(
+        loader::Deploy {
+            payer: &ctx.accounts.treasury.to_account_info(),
+            program_payer: &ctx.accounts.deployer.to_account_info(),
+            program: &ctx.accounts.hosted_program.to_account_info(),
+            programdata: &ctx.accounts.programdata.to_account_info(),
+            buffer: &ctx.accounts.buffer.to_account_info(),
+            authority: &ctx.accounts.authority.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+            clock: &ctx.accounts.clock.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            loader: &ctx.accounts.loader.to_account_info(),
+        },
+        program_lamports,
+        program_len,
+        &[
+            &[TREASURY_SEED, &[config.treasury_bump]],
+            &[AUTHORITY_SEED, &[ctx.bumps.authority]],
+        ],
+    )?;
+
+    let clock = &ctx.accounts.clock;
+    let deployment = &mut ctx.accounts.deployment;
+    deployment.status = DeploymentStatus::Hosted;
+    deployment.deploy_slot = clock.slot;
+    deployment.hosted_at = clock.unix_timestamp;
+    deployment.expires_at = clock
+        .unix_timestamp
+        .checked_add(config.hosting_duration)
+        .ok_or(ErrorCode::InvalidHostingDuration)?;
+    deployment.rent_sponsored = programdata_lamports;
+
+    emit_cpi!(ProgramDeployed {
+        deployment: deployment.key(),
+        program: deployment.program,
+        deploy_slot: deployment.deploy_slot,
+        expires_at: deployment.expires_at,
+        rent_sponsored: deployment.rent_sponsored,
+    });
+
+    Ok(())
+}
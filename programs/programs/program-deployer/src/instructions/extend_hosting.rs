@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::{CONFIG_SEED, DEPLOYMENT_SEED, SECONDS_PER_DAY, TREASURY_SEED};
+use crate::error::ErrorCode;
+use crate::events::HostingExtended;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment};
+
+/// Pays for extra hosting days. Anyone may pay, e.g. a teammate of the
+/// deployer. Fees are in SOL unless the token accounts are given.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendHosting<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump, has_one = treasury)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(
+        mut,
+        seeds = [DEPLOYMENT_SEED, deployment.program.as_ref()],
+        bump = deployment.bump
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    #[account(mut, seeds = [TREASURY_SEED], bump = config.treasury_bump)]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub(crate) fn handler(ctx: Context<ExtendHosting>, days: u16) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let deployment = &ctx.accounts.deployment;
+
+    match deployment.status {
+        DeploymentStatus::Hosted => {}
+        DeploymentStatus::Registered => return err!(ErrorCode::NotDeployed),
+        DeploymentStatus::Claimed => return err!(ErrorCode::AlreadyClaimed),
+        DeploymentStatus::Reclaimed => return err!(ErrorCode::AlreadyReclaimed),
+    }
+    require!(
+        !deployment.is_expired(Clock::get()?.unix_timestamp),
+        ErrorCode::Expired
+    );
+    require!(days > 0, ErrorCode::InvalidExtension);
+
+    let expires_at = deployment
+        .expires_at
+        .checked_add(i64::from(days) * SECONDS_PER_DAY)
+        .ok_or(ErrorCode::HostingPeriodExceeded)?;
+    require!(
+        expires_at.saturating_sub(deployment.hosted_at) <= config.max_hosting_period,
+        ErrorCode::HostingPeriodExceeded
+    );
+
+    let accounts = &ctx.accounts;
+    let (fee, fee_mint) = match (
+        &accounts.fee_mint,
+        &accounts.payer_token_account,
+        &accounts.treasury_token_account,
+        &accounts.token_program,
+    ) {
+        (None, None, None, None) => {
+            let fee = config
+                .extension_fee
+                .checked_mul(u64::from(days))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            if fee > 0 {
+                transfer(
+                    CpiContext::new(
+                        accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: accounts.payer.to_account_info(),
+                            to: accounts.treasury.to_account_info(),
+                        },
+                    ),
+                    fee,
+                )?;
+            }
+            (fee, None)
+        }
+        (Some(mint), Some(from), Some(to), Some(token_program)) => {
+            let accepted = config.fee_mint.ok_or(ErrorCode::TokenFeesDisabled)?;
+            require_keys_eq!(mint.key(), accepted, ErrorCode::InvalidFeeAccounts);
+            require!(
+                to.mint == accepted && to.owner == accounts.treasury.key(),
+                ErrorCode::InvalidFeeAccounts
+            );
+
+            let fee = config
+                .extension_token_fee
+                .checked_mul(u64::from(days))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            if fee > 0 {
+                transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: from.to_account_info(),
+                            mint: mint.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: accounts.payer.to_account_info(),
+                        },
+                    ),
+                    fee,
+                    mint.decimals,
+                )?;
+            }
+            (fee, Some(accepted))
+        }
+        _ => return err!(ErrorCode::InvalidFeeAccounts),
+    };
+
+    let deployment = &mut ctx.accounts.deployment;
+    deployment.expires_at = expires_at;
+
+    emit_cpi!(HostingExtended {
+        deployment: deployment.key(),
+        program: deployment.program,
+        expires_at,
+        fee,
+        fee_mint,
+    });
+
+    Ok(())
+}
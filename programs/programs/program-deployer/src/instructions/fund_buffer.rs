@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::constants::{AUTHORITY_SEED, CONFIG_SEED, DEPLOYMENT_SEED, TREASURY_SEED};
+use crate::error::ErrorCode;
+use crate::loader;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment};
+
+#[derive(Accounts)]
+pub struct FundBuffer<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump, has_one = treasury)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(
+        seeds = [DEPLOYMENT_SEED, deployment.program.as_ref()],
+        bump = deployment.bump,
+        has_one = deployer @ ErrorCode::UnauthorizedDeployer,
+        has_one = buffer,
+        constraint = deployment.status == DeploymentStatus::Registered @ ErrorCode::AlreadyDeployed
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    #[account(mut, seeds = [TREASURY_SEED], bump = config.treasury_bump)]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: PDA set as the buffer authority, so only this program can write
+    /// to or close the sponsored buffer
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buffer: Signer<'info>,
+
+    pub deployer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub loader: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<FundBuffer>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.paused, ErrorCode::Paused);
+
+    let program_len = ctx.accounts.deployment.program_size as usize;
+    let lamports =
+        Rent::get()?.minimum_balance(UpgradeableLoaderState::size_of_buffer(program_len));
+
+    loader::create_buffer(
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.buffer.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.loader.to_account_info(),
+        lamports,
+        program_len,
+        &[&[TREASURY_SEED, &[config.treasury_bump]]],
+    )
+}
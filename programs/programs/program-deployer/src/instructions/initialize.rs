@@ -1,9 +1,60 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{CONFIG_SEED, TREASURY_SEED};
+use crate::program::ProgramDeployer;
+use crate::state::{ConfigParams, DeployerConfig};
+
 #[derive(Accounts)]
-pub struct Initialize {}
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + DeployerConfig::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(seeds = [TREASURY_SEED], bump)]
+    pub treasury: SystemAccount<'info>,
+
+    /// Only the program's upgrade authority may create the config, so it
+    /// can't be front-run after a deploy
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, ProgramDeployer>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()))]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<Initialize>, params: ConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.set_inner(DeployerConfig {
+        admin: ctx.accounts.admin.key(),
+        treasury: ctx.accounts.treasury.key(),
+        attestation_key: params.attestation_key,
+        hosting_duration: params.hosting_duration,
+        max_program_size: params.max_program_size,
+        max_deployments_per_user: params.max_deployments_per_user,
+        max_hosted_bytes_per_user: params.max_hosted_bytes_per_user,
+        quota_window: params.quota_window,
+        max_sponsored_lamports_per_user: params.max_sponsored_lamports_per_user,
+        claim_fee: params.claim_fee,
+        reclaim_reward: params.reclaim_reward,
+        max_hosting_period: params.max_hosting_period,
+        extension_fee: params.extension_fee,
+        fee_mint: params.fee_mint,
+        extension_token_fee: params.extension_token_fee,
+        paused: false,
+        bump: ctx.bumps.config,
+        treasury_bump: ctx.bumps.treasury,
+    });
 
-pub fn handler(ctx: Context<Initialize>) -> Result<()> {
-    msg!("Greetings from: {:?}", ctx.program_id);
-    Ok(())
+    config.validate()
 }
@@ -1,3 +1,21 @@
+pub mod claim_authority;
+pub mod deploy_program;
+pub mod extend_hosting;
+pub mod fund_buffer;
 pub mod initialize;
+pub mod reclaim_expired;
+pub mod register_deployment;
+pub mod update_config;
+pub mod upgrade_hosted;
+pub mod write_buffer;
 
+pub use claim_authority::*;
+pub use deploy_program::*;
+pub use extend_hosting::*;
+pub use fund_buffer::*;
 pub use initialize::*;
+pub use reclaim_expired::*;
+pub use register_deployment::*;
+pub use update_config::*;
+pub use upgrade_hosted::*;
+pub use write_buffer::*;
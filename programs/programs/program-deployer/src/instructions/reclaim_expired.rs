@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use solana_loader_v3_interface::get_program_data_address;
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::constants::{AUTHORITY_SEED, CONFIG_SEED, TREASURY_SEED, USER_QUOTA_SEED};
+use crate::error::ErrorCode;
+use crate::events::DeploymentReclaimed;
+use crate::loader;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment, UserQuota};
+
+/// Remaining accounts per deployment: the record, the deployer's quota, its
+/// program, and either the programdata (deployed) or the buffer (never deployed)
+const ACCOUNTS_PER_DEPLOYMENT: usize = 4;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimExpired<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump, has_one = treasury)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(mut, seeds = [TREASURY_SEED], bump = config.treasury_bump)]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: PDA that is the upgrade and buffer authority
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Anyone may crank; receives the reclaim reward
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub loader: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReclaimExpired<'info>>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let groups = remaining.chunks_exact(ACCOUNTS_PER_DEPLOYMENT);
+    require!(
+        groups.len() > 0 && groups.remainder().is_empty(),
+        ErrorCode::InvalidReclaimAccounts
+    );
+
+    let reclaim_reward = ctx.accounts.config.reclaim_reward;
+    let mut reward: u64 = 0;
+    let now = Clock::get()?.unix_timestamp;
+    let authority_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.authority]];
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let loader_program = ctx.accounts.loader.to_account_info();
+
+    for accounts in groups {
+        let [deployment_info, quota_info, program, data] = accounts else {
+            unreachable!("chunks are exactly ACCOUNTS_PER_DEPLOYMENT long");
+        };
+
+        let mut deployment = Account::<HostedDeployment>::try_from(deployment_info)?;
+        require_keys_eq!(
+            deployment.program,
+            program.key(),
+            ErrorCode::InvalidReclaimAccounts
+        );
+        require!(deployment.is_expired(now), ErrorCode::NotExpired);
+
+        let mut quota = Account::<UserQuota>::try_from(quota_info)?;
+        let quota_address = Pubkey::create_program_address(
+            &[USER_QUOTA_SEED, deployment.deployer.as_ref(), &[quota.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| ErrorCode::InvalidReclaimAccounts)?;
+        require_keys_eq!(
+            quota_address,
+            quota_info.key(),
+            ErrorCode::InvalidReclaimAccounts
+        );
+
+        let treasury_balance = treasury.lamports();
+
+        // Entries whose account isn't the deployment's, or isn't controlled by
+        // the authority PDA, are skipped rather than failing the whole batch
+        match deployment.status {
+            DeploymentStatus::Hosted => {
+                if data.key() != get_program_data_address(program.key)
+                    || loader::authority(data) != Some(authority.key())
+                {
+                    continue;
+                }
+                loader::close(
+                    data,
+                    &treasury,
+                    &authority,
+                    Some(program),
+                    &loader_program,
+                    &[authority_seeds],
+                )?;
+            }
+            DeploymentStatus::Registered => {
+                if data.key() != deployment.buffer {
+                    continue;
+                }
+                // The buffer only exists if it was funded
+                if data.owner == &bpf_loader_upgradeable::ID {
+                    if loader::authority(data) != Some(authority.key()) {
+                        continue;
+                    }
+                    loader::close(
+                        data,
+                        &treasury,
+                        &authority,
+                        None,
+                        &loader_program,
+                        &[authority_seeds],
+                    )?;
+                }
+            }
+            DeploymentStatus::Claimed => return err!(ErrorCode::AlreadyClaimed),
+            DeploymentStatus::Reclaimed => return err!(ErrorCode::AlreadyReclaimed),
+        }
+
+        deployment.status = DeploymentStatus::Reclaimed;
+        deployment.exit(ctx.program_id)?;
+
+        quota.release(deployment.program_size);
+        quota.exit(ctx.program_id)?;
+
+        // Only pay for deployments that returned more than the reward, so
+        // reclaiming unfunded registrations can't drain the treasury
+        let lamports_reclaimed = treasury.lamports().saturating_sub(treasury_balance);
+        if lamports_reclaimed > reclaim_reward {
+            reward = reward
+                .checked_add(reclaim_reward)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+
+        emit_cpi!(DeploymentReclaimed {
+            deployment: deployment.key(),
+            program: deployment.program,
+            cranker: ctx.accounts.cranker.key(),
+            lamports_reclaimed,
+        });
+    }
+
+    if reward > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: treasury,
+                    to: ctx.accounts.cranker.to_account_info(),
+                },
+                &[&[TREASURY_SEED, &[ctx.accounts.config.treasury_bump]]],
+            ),
+            reward,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+
+use crate::attestation::{self, AttestationAction, DeployAttestation};
+use crate::constants::{CONFIG_SEED, DEPLOYMENT_SEED, NONCE_SEED, USER_QUOTA_SEED};
+use crate::error::ErrorCode;
+use crate::events::DeploymentRegistered;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment, UsedNonce, UserQuota};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RegisterDeploymentParams {
+    /// Address the program will be deployed to
+    pub program: Pubkey,
+    /// Buffer that will hold the program bytes
+    pub buffer: Pubkey,
+    /// Size of the program in bytes
+    pub program_size: u32,
+    /// SHA-256 of the program bytes
+    pub program_hash: [u8; 32],
+    /// Expiry of the API attestation
+    pub attestation_expiry: i64,
+    /// Attestation nonce, spent by this registration
+    pub nonce: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: RegisterDeploymentParams)]
+pub struct RegisterDeployment<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(
+        init,
+        payer = deployer,
+        space = 8 + HostedDeployment::INIT_SPACE,
+        seeds = [DEPLOYMENT_SEED, params.program.as_ref()],
+        bump
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    #[account(
+        init_if_needed,
+        payer = deployer,
+        space = 8 + UserQuota::INIT_SPACE,
+        seeds = [USER_QUOTA_SEED, deployer.key().as_ref()],
+        bump
+    )]
+    pub user_quota: Account<'info, UserQuota>,
+
+    /// Fails to initialize if the attestation was already used
+    #[account(
+        init,
+        payer = deployer,
+        space = 8 + UsedNonce::INIT_SPACE,
+        seeds = [NONCE_SEED, &params.nonce.to_le_bytes()],
+        bump
+    )]
+    pub used_nonce: Account<'info, UsedNonce>,
+
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+
+    /// CHECK: the instructions sysvar, read to find the attestation
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<RegisterDeployment>,
+    params: RegisterDeploymentParams,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    require!(!config.paused, ErrorCode::Paused);
+    require!(params.program_size > 0, ErrorCode::EmptyProgram);
+    require!(
+        params.program_size <= config.max_program_size,
+        ErrorCode::ProgramTooLarge
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= params.attestation_expiry,
+        ErrorCode::AttestationExpired
+    );
+    attestation::verify(
+        &ctx.accounts.instructions,
+        &config.attestation_key,
+        &DeployAttestation {
+            user: ctx.accounts.deployer.key(),
+            action: AttestationAction::Register,
+            program: params.program,
+            program_hash: params.program_hash,
+            program_size: params.program_size,
+            expiry: params.attestation_expiry,
+            nonce: params.nonce,
+        },
+    )?;
+
+    ctx.accounts.used_nonce.set_inner(UsedNonce {
+        user: ctx.accounts.deployer.key(),
+        used_at: clock.unix_timestamp,
+    });
+
+    let programdata_len = UpgradeableLoaderState::size_of_programdata(params.program_size as usize);
+    let rent_sponsored = Rent::get()?.minimum_balance(programdata_len);
+
+    let quota = &mut ctx.accounts.user_quota;
+    quota.user = ctx.accounts.deployer.key();
+    quota.bump = ctx.bumps.user_quota;
+    quota.roll_window(clock.unix_timestamp, config.quota_window);
+
+    require!(
+        quota.active_deployments < config.max_deployments_per_user,
+        ErrorCode::TooManyDeployments
+    );
+
+    let hosted_bytes = quota
+        .hosted_bytes
+        .saturating_add(u64::from(params.program_size));
+    require!(
+        hosted_bytes <= config.max_hosted_bytes_per_user,
+        ErrorCode::HostedBytesExceeded
+    );
+
+    let window_sponsored_lamports = quota
+        .window_sponsored_lamports
+        .saturating_add(rent_sponsored);
+    require!(
+        window_sponsored_lamports <= config.max_sponsored_lamports_per_user,
+        ErrorCode::SponsoredLamportsExceeded
+    );
+
+    quota.active_deployments += 1;
+    quota.hosted_bytes = hosted_bytes;
+    quota.window_sponsored_lamports = window_sponsored_lamports;
+
+    ctx.accounts.deployment.set_inner(HostedDeployment {
+        deployer: ctx.accounts.deployer.key(),
+        program: params.program,
+        buffer: params.buffer,
+        program_size: params.program_size,
+        program_hash: params.program_hash,
+        deploy_slot: clock.slot,
+        hosted_at: clock.unix_timestamp,
+        expires_at: clock
+            .unix_timestamp
+            .checked_add(config.hosting_duration)
+            .ok_or(ErrorCode::InvalidHostingDuration)?,
+        status: DeploymentStatus::Registered,
+        rent_sponsored,
+        upgrade_count: 0,
+        last_upgrade_slot: 0,
+        last_buffer_hash: [0; 32],
+        bump: ctx.bumps.deployment,
+    });
+
+    let deployment = &ctx.accounts.deployment;
+    emit_cpi!(DeploymentRegistered {
+        deployment: deployment.key(),
+        deployer: deployment.deployer,
+        program: deployment.program,
+        program_size: deployment.program_size,
+        program_hash: deployment.program_hash,
+        expires_at: deployment.expires_at,
+    });
+
+    Ok(())
+}
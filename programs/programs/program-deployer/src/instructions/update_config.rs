@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONFIG_SEED;
+use crate::error::ErrorCode;
+use crate::state::{DeployerConfig, UpdateConfigParams};
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, DeployerConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if let Some(admin) = params.admin {
+        config.admin = admin;
+    }
+    if let Some(attestation_key) = params.attestation_key {
+        config.attestation_key = attestation_key;
+    }
+    if let Some(hosting_duration) = params.hosting_duration {
+        config.hosting_duration = hosting_duration;
+    }
+    if let Some(max_program_size) = params.max_program_size {
+        config.max_program_size = max_program_size;
+    }
+    if let Some(max_deployments_per_user) = params.max_deployments_per_user {
+        config.max_deployments_per_user = max_deployments_per_user;
+    }
+    if let Some(max_hosted_bytes_per_user) = params.max_hosted_bytes_per_user {
+        config.max_hosted_bytes_per_user = max_hosted_bytes_per_user;
+    }
+    if let Some(quota_window) = params.quota_window {
+        config.quota_window = quota_window;
+    }
+    if let Some(max_sponsored_lamports_per_user) = params.max_sponsored_lamports_per_user {
+        config.max_sponsored_lamports_per_user = max_sponsored_lamports_per_user;
+    }
+    if let Some(claim_fee) = params.claim_fee {
+        config.claim_fee = claim_fee;
+    }
+    if let Some(reclaim_reward) = params.reclaim_reward {
+        config.reclaim_reward = reclaim_reward;
+    }
+    if let Some(max_hosting_period) = params.max_hosting_period {
+        config.max_hosting_period = max_hosting_period;
+    }
+    if let Some(extension_fee) = params.extension_fee {
+        config.extension_fee = extension_fee;
+    }
+    if let Some(fee_mint) = params.fee_mint {
+        config.fee_mint = fee_mint;
+    }
+    if let Some(extension_token_fee) = params.extension_token_fee {
+        config.extension_token_fee = extension_token_fee;
+    }
+    if let Some(paused) = params.paused {
+        config.paused = paused;
+    }
+
+    config.validate()
+}
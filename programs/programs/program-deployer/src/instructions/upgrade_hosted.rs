@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar;
+use solana_loader_v3_interface::{get_program_data_address, state::UpgradeableLoaderState};
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::attestation::{self, AttestationAction, DeployAttestation};
+use crate::constants::{AUTHORITY_SEED, CONFIG_SEED, DEPLOYMENT_SEED, NONCE_SEED};
+use crate::error::ErrorCode;
+use crate::events::HostedProgramUpgraded;
+use crate::loader;
+use crate::state::{DeployerConfig, DeploymentStatus, HostedDeployment, UsedNonce};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpgradeHostedParams {
+    /// Size of the new program in bytes
+    pub program_size: u32,
+    /// SHA-256 of the new program bytes
+    pub program_hash: [u8; 32],
+    /// Expiry of the API attestation
+    pub attestation_expiry: i64,
+    /// Attestation nonce, spent by this upgrade
+    pub nonce: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: UpgradeHostedParams)]
+pub struct UpgradeHosted<'info> {
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, DeployerConfig>,
+
+    #[account(
+        mut,
+        seeds = [DEPLOYMENT_SEED, hosted_program.key().as_ref()],
+        bump = deployment.bump,
+        has_one = deployer @ ErrorCode::UnauthorizedDeployer
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    /// CHECK: PDA that is the program's upgrade authority and must be the
+    /// buffer authority
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: the hosted program, bound to the deployment by its seeds
+    #[account(mut)]
+    pub hosted_program: UncheckedAccount<'info>,
+
+    /// CHECK: the program's programdata account
+    #[account(
+        mut,
+        address = get_program_data_address(&hosted_program.key())
+    )]
+    pub programdata: UncheckedAccount<'info>,
+
+    /// CHECK: buffer with the new program bytes, validated by the loader
+    #[account(mut, owner = bpf_loader_upgradeable::ID)]
+    pub buffer: UncheckedAccount<'info>,
+
+    /// Fails to initialize if the attestation was already used
+    #[account(
+        init,
+        payer = deployer,
+        space = 8 + UsedNonce::INIT_SPACE,
+        seeds = [NONCE_SEED, &params.nonce.to_le_bytes()],
+        bump
+    )]
+    pub used_nonce: Account<'info, UsedNonce>,
+
+    /// Funded the buffer, so receives its lamports back as the spill account
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+
+    /// CHECK: the instructions sysvar, read to find the attestation
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: the BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub loader: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<UpgradeHosted>, params: UpgradeHostedParams) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let deployment = &ctx.accounts.deployment;
+    let clock = &ctx.accounts.clock;
+
+    require!(!config.paused, ErrorCode::Paused);
+    match deployment.status {
+        DeploymentStatus::Hosted => {}
+        DeploymentStatus::Registered => return err!(ErrorCode::NotDeployed),
+        DeploymentStatus::Claimed => return err!(ErrorCode::AlreadyClaimed),
+        DeploymentStatus::Reclaimed => return err!(ErrorCode::AlreadyReclaimed),
+    }
+    require!(
+        !deployment.is_expired(clock.unix_timestamp),
+        ErrorCode::Expired
+    );
+
+    // The programdata account was sized for the registered program, and the
+    // treasury does not sponsor growing it
+    let (program_size, buffer_hash) = {
+        let data = ctx.accounts.buffer.try_borrow_data()?;
+        let program_bytes = data
+            .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+            .filter(|bytes| !bytes.is_empty())
+            .ok_or(ErrorCode::EmptyProgram)?;
+        let program_size =
+            u32::try_from(program_bytes.len()).map_err(|_| ErrorCode::ProgramTooLarge)?;
+        (program_size, hash(program_bytes).to_bytes())
+    };
+    require!(
+        program_size <= config.max_program_size,
+        ErrorCode::ProgramTooLarge
+    );
+    require!(
+        program_size <= deployment.program_size,
+        ErrorCode::UpgradeTooLarge
+    );
+
+    // Only upgrade to bytes the API attested
+    require!(
+        clock.unix_timestamp <= params.attestation_expiry,
+        ErrorCode::AttestationExpired
+    );
+    require!(
+        program_size == params.program_size && buffer_hash == params.program_hash,
+        ErrorCode::ProgramHashMismatch
+    );
+    attestation::verify(
+        &ctx.accounts.instructions,
+        &config.attestation_key,
+        &DeployAttestation {
+            user: ctx.accounts.deployer.key(),
+            action: AttestationAction::Upgrade,
+            program: ctx.accounts.hosted_program.key(),
+            program_hash: params.program_hash,
+            program_size: params.program_size,
+            expiry: params.attestation_expiry,
+            nonce: params.nonce,
+        },
+    )?;
+
+    ctx.accounts.used_nonce.set_inner(UsedNonce {
+        user: ctx.accounts.deployer.key(),
+        used_at: clock.unix_timestamp,
+    });
+
+    loader::upgrade(
+        loader::Upgrade {
+            program: &ctx.accounts.hosted_program.to_account_info(),
+            programdata: &ctx.accounts.programdata.to_account_info(),
+            buffer: &ctx.accounts.buffer.to_account_info(),
+            spill: &ctx.accounts.deployer.to_account_info(),
+            authority: &ctx.accounts.authority.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+            clock: &ctx.accounts.clock.to_account_info(),
+            loader: &ctx.accounts.loader.to_account_info(),
+        },
+        &[&[AUTHORITY_SEED, &[ctx.bumps.authority]]],
+    )?;
+
+    let slot = ctx.accounts.clock.slot;
+    let deployment = &mut ctx.accounts.deployment;
+    deployment.upgrade_count = deployment
+        .upgrade_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    deployment.last_upgrade_slot = slot;
+    deployment.last_buffer_hash = buffer_hash;
+    deployment.program_hash = buffer_hash;
+
+    emit_cpi!(HostedProgramUpgraded {
+        deployment: deployment.key(),
+        program: deployment.program,
+        upgrade_count: deployment.upgrade_count,
+        slot,
+        program_size,
+        buffer_hash,
+    });
+
+    Ok(())
+}
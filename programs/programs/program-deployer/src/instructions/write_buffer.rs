@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::constants::{AUTHORITY_SEED, DEPLOYMENT_SEED};
+use crate::error::ErrorCode;
+use crate::loader;
+use crate::state::{DeploymentStatus, HostedDeployment};
+
+#[derive(Accounts)]
+pub struct WriteBuffer<'info> {
+    #[account(
+        seeds = [DEPLOYMENT_SEED, deployment.program.as_ref()],
+        bump = deployment.bump,
+        has_one = deployer @ ErrorCode::UnauthorizedDeployer,
+        has_one = buffer,
+        constraint = deployment.status == DeploymentStatus::Registered @ ErrorCode::AlreadyDeployed
+    )]
+    pub deployment: Account<'info, HostedDeployment>,
+
+    /// CHECK: PDA that is the buffer authority
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: the deployment's buffer, validated by `has_one` and the loader
+    #[account(mut)]
+    pub buffer: UncheckedAccount<'info>,
+
+    pub deployer: Signer<'info>,
+
+    /// CHECK: the BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub loader: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<WriteBuffer>, offset: u32, bytes: Vec<u8>) -> Result<()> {
+    loader::write(
+        &ctx.accounts.buffer.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.loader.to_account_info(),
+        offset,
+        bytes,
+        &[&[AUTHORITY_SEED, &[ctx.bumps.authority]]],
+    )
+}
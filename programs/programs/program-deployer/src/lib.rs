@@ -1,21 +1,104 @@
+// Anchor's injected IDL handlers, which `#[program]` expands to at the crate
+// root, call `AccountInfo::realloc` (deprecated in favour of `resize`). There's
+// no item to scope the allow to, so every module of ours opts back in below.
+#![allow(deprecated)]
+
+#[warn(deprecated)]
+pub mod attestation;
+#[warn(deprecated)]
 pub mod constants;
+#[warn(deprecated)]
 pub mod error;
+#[warn(deprecated)]
+pub mod events;
+#[warn(deprecated)]
 pub mod instructions;
+#[warn(deprecated)]
+pub mod loader;
+#[warn(deprecated)]
 pub mod state;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
 declare_id!("54ER83fowDBHn76bJm9bCMC2gTXb8RcvMgdNYkrkcngp");
 
+#[warn(deprecated)]
 #[program]
 pub mod program_deployer {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        initialize::handler(ctx)
+    /// Create the global config; only the program's upgrade authority may call this
+    pub fn initialize(ctx: Context<Initialize>, params: ConfigParams) -> Result<()> {
+        initialize::handler(ctx, params)
+    }
+
+    /// Change config values, gated on the config admin
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        update_config::handler(ctx, params)
+    }
+
+    /// Record a hosted deployment for the signer, checked against the config
+    /// limits and the signer's `UserQuota`
+    ///
+    /// Must directly follow an Ed25519Program instruction verifying the API's
+    /// `DeployAttestation` for this request.
+    pub fn register_deployment(
+        ctx: Context<RegisterDeployment>,
+        params: RegisterDeploymentParams,
+    ) -> Result<()> {
+        register_deployment::handler(ctx, params)
+    }
+
+    /// Create the deployment's buffer with rent paid by the treasury
+    pub fn fund_buffer(ctx: Context<FundBuffer>) -> Result<()> {
+        fund_buffer::handler(ctx)
+    }
+
+    /// Write program bytes into the sponsored buffer
+    pub fn write_buffer(ctx: Context<WriteBuffer>, offset: u32, bytes: Vec<u8>) -> Result<()> {
+        write_buffer::handler(ctx, offset, bytes)
+    }
+
+    /// Deploy the buffer through the upgradeable loader, with the authority
+    /// PDA as upgrade authority and the treasury paying rent
+    pub fn deploy_program(ctx: Context<DeployProgram>) -> Result<()> {
+        deploy_program::handler(ctx)
+    }
+
+    /// Hand the upgrade authority to the deployer, who reimburses the
+    /// sponsored rent plus the claim fee to the treasury
+    pub fn claim_authority(ctx: Context<ClaimAuthority>) -> Result<()> {
+        claim_authority::handler(ctx)
+    }
+
+    /// Push back a hosted program's expiry by `days`, paid to the treasury in
+    /// SOL or in the configured fee token
+    pub fn extend_hosting(ctx: Context<ExtendHosting>, days: u16) -> Result<()> {
+        extend_hosting::handler(ctx, days)
+    }
+
+    /// Upgrade a hosted program from a buffer the deployer wrote and handed to
+    /// the authority PDA, within the space allocated at deployment
+    ///
+    /// Must directly follow an Ed25519Program instruction verifying the API's
+    /// `DeployAttestation` for the new program bytes.
+    pub fn upgrade_hosted(ctx: Context<UpgradeHosted>, params: UpgradeHostedParams) -> Result<()> {
+        upgrade_hosted::handler(ctx, params)
+    }
+
+    /// Permissionless crank that closes expired, unclaimed deployments passed as
+    /// `[deployment, user quota, program, programdata or buffer]` remaining
+    /// accounts and returns their rent to the treasury. Entries whose programdata
+    /// or buffer isn't the deployment's, or isn't under the authority PDA, are
+    /// skipped.
+    pub fn reclaim_expired<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReclaimExpired<'info>>,
+    ) -> Result<()> {
+        reclaim_expired::handler(ctx)
     }
 }
@@ -0,0 +1,256 @@
+//! CPI helpers for the BPF upgradeable loader
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use solana_loader_v3_interface::instruction as loader_instruction;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+/// Create a buffer funded by `payer` with `authority` as its buffer authority
+#[allow(clippy::too_many_arguments)]
+pub fn create_buffer<'info>(
+    payer: &AccountInfo<'info>,
+    buffer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    loader: &AccountInfo<'info>,
+    lamports: u64,
+    program_len: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instructions = loader_instruction::create_buffer(
+        payer.key,
+        buffer.key,
+        authority.key,
+        lamports,
+        program_len,
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+
+    invoke_all(
+        &instructions,
+        &[
+            payer.clone(),
+            buffer.clone(),
+            authority.clone(),
+            system_program.clone(),
+            loader.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// Write `bytes` into a buffer at `offset`
+pub fn write<'info>(
+    buffer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    loader: &AccountInfo<'info>,
+    offset: u32,
+    bytes: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instruction = loader_instruction::write(buffer.key, authority.key, offset, bytes);
+
+    invoke_all(
+        &[instruction],
+        &[buffer.clone(), authority.clone(), loader.clone()],
+        signer_seeds,
+    )
+}
+
+/// Accounts for `DeployWithMaxDataLen`
+pub struct Deploy<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    /// Funds the program account, which the loader can never close
+    pub program_payer: &'a AccountInfo<'info>,
+    pub program: &'a AccountInfo<'info>,
+    pub programdata: &'a AccountInfo<'info>,
+    pub buffer: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub loader: &'a AccountInfo<'info>,
+}
+
+/// Create the program account and deploy the buffer into it
+///
+/// The program account is funded by `program_payer`. The buffer's lamports are
+/// returned to `payer`, which then pays for the programdata account.
+// Loader v3 marks deploys as deprecated in favour of loader v4, which hosted
+// programs don't target yet
+#[allow(deprecated)]
+pub fn deploy(
+    accounts: Deploy<'_, '_>,
+    program_lamports: u64,
+    max_data_len: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.program_payer.clone(),
+                to: accounts.program.clone(),
+            },
+        ),
+        program_lamports,
+        UpgradeableLoaderState::size_of_program() as u64,
+        &bpf_loader_upgradeable::ID,
+    )?;
+
+    // The helper pairs the deploy with a `create_account` funded by `payer`,
+    // which is done above instead
+    let instructions = loader_instruction::deploy_with_max_program_len(
+        accounts.payer.key,
+        accounts.program.key,
+        accounts.buffer.key,
+        accounts.authority.key,
+        program_lamports,
+        max_data_len,
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+    let deploy = instructions.last().ok_or(ProgramError::InvalidArgument)?;
+
+    invoke_all(
+        std::slice::from_ref(deploy),
+        &[
+            accounts.payer.clone(),
+            accounts.programdata.clone(),
+            accounts.program.clone(),
+            accounts.buffer.clone(),
+            accounts.rent.clone(),
+            accounts.clock.clone(),
+            accounts.system_program.clone(),
+            accounts.authority.clone(),
+            accounts.loader.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// Accounts for `Upgrade`
+pub struct Upgrade<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub programdata: &'a AccountInfo<'info>,
+    pub buffer: &'a AccountInfo<'info>,
+    pub spill: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub loader: &'a AccountInfo<'info>,
+}
+
+/// Replace a program's code with the buffer's, sending the buffer's lamports
+/// and any excess programdata lamports to `spill`
+pub fn upgrade(accounts: Upgrade<'_, '_>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    let instruction = loader_instruction::upgrade(
+        accounts.program.key,
+        accounts.buffer.key,
+        accounts.authority.key,
+        accounts.spill.key,
+    );
+
+    invoke_all(
+        &[instruction],
+        &[
+            accounts.programdata.clone(),
+            accounts.program.clone(),
+            accounts.buffer.clone(),
+            accounts.spill.clone(),
+            accounts.rent.clone(),
+            accounts.clock.clone(),
+            accounts.authority.clone(),
+            accounts.loader.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// Hand a program's upgrade authority to `new_authority`, which must sign
+pub fn set_upgrade_authority<'info>(
+    program: &AccountInfo<'info>,
+    programdata: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    new_authority: &AccountInfo<'info>,
+    loader: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instruction = loader_instruction::set_upgrade_authority_checked(
+        program.key,
+        authority.key,
+        new_authority.key,
+    );
+
+    invoke_all(
+        &[instruction],
+        &[
+            programdata.clone(),
+            authority.clone(),
+            new_authority.clone(),
+            loader.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// The authority of a loader buffer or programdata account, `None` if it has
+/// none or is neither
+pub fn authority(account: &AccountInfo) -> Option<Pubkey> {
+    if account.owner != &bpf_loader_upgradeable::ID {
+        return None;
+    }
+
+    // A u32 variant tag, the programdata's deploy slot, then an Option<Pubkey>
+    let data = account.try_borrow_data().ok()?;
+    let offset = match data.get(..4)? {
+        [1, 0, 0, 0] => 4,
+        [3, 0, 0, 0] => 12,
+        _ => return None,
+    };
+    match data.get(offset)? {
+        1 => Pubkey::try_from(data.get(offset + 1..offset + 33)?).ok(),
+        _ => None,
+    }
+}
+
+/// Close a buffer, or a programdata account when `program` is given, sending
+/// its lamports to `recipient`
+pub fn close<'info>(
+    account: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    program: Option<&AccountInfo<'info>>,
+    loader: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let instruction = loader_instruction::close_any(
+        account.key,
+        recipient.key,
+        Some(authority.key),
+        program.map(|program| program.key),
+    );
+
+    let mut account_infos = vec![
+        account.clone(),
+        recipient.clone(),
+        authority.clone(),
+        loader.clone(),
+    ];
+    account_infos.extend(program.cloned());
+
+    invoke_all(&[instruction], &account_infos, signer_seeds)
+}
+
+fn invoke_all(
+    instructions: &[Instruction],
+    account_infos: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    for instruction in instructions {
+        invoke_signed(instruction, account_infos, signer_seeds)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PERMITTED_PROGRAM_SIZE;
+use crate::error::ErrorCode;
+
+/// Global settings for hosted deployments, stored at `[CONFIG_SEED]`
+#[account]
+#[derive(InitSpace)]
+pub struct DeployerConfig {
+    /// May change these settings
+    pub admin: Pubkey,
+    /// PDA that sponsors buffer rent and receives fees
+    pub treasury: Pubkey,
+    /// API key that must attest every deployment
+    pub attestation_key: Pubkey,
+    /// Seconds a hosted program stays deployed before it can be reclaimed
+    pub hosting_duration: i64,
+    /// Largest program, in bytes, that may be hosted
+    pub max_program_size: u32,
+    /// Hosted deployments a single wallet may have at once
+    pub max_deployments_per_user: u16,
+    /// Program bytes a single wallet may have hosted at once
+    pub max_hosted_bytes_per_user: u64,
+    /// Length in seconds of the window `max_sponsored_lamports_per_user` applies to
+    pub quota_window: i64,
+    /// Rent the treasury may sponsor for a single wallet per window
+    pub max_sponsored_lamports_per_user: u64,
+    /// Lamports charged on top of the rent reimbursement when claiming a program
+    pub claim_fee: u64,
+    /// Lamports paid from the treasury for each deployment a crank reclaims,
+    /// if closing it returned more than this
+    pub reclaim_reward: u64,
+    /// Longest a program may stay hosted, counted from deployment, including extensions
+    pub max_hosting_period: i64,
+    /// Lamports charged per day of extension
+    pub extension_fee: u64,
+    /// Stable token accepted for extension fees, if any
+    pub fee_mint: Option<Pubkey>,
+    /// `fee_mint` base units charged per day of extension
+    pub extension_token_fee: u64,
+    /// Rejects new deployments while set
+    pub paused: bool,
+    pub bump: u8,
+    pub treasury_bump: u8,
+}
+
+/// Settings chosen when the config is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfigParams {
+    pub attestation_key: Pubkey,
+    pub hosting_duration: i64,
+    pub max_program_size: u32,
+    pub max_deployments_per_user: u16,
+    pub max_hosted_bytes_per_user: u64,
+    pub quota_window: i64,
+    pub max_sponsored_lamports_per_user: u64,
+    pub claim_fee: u64,
+    pub reclaim_reward: u64,
+    pub max_hosting_period: i64,
+    pub extension_fee: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub extension_token_fee: u64,
+}
+
+/// Settings to change; `None` leaves a value as it is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UpdateConfigParams {
+    pub admin: Option<Pubkey>,
+    pub attestation_key: Option<Pubkey>,
+    pub hosting_duration: Option<i64>,
+    pub max_program_size: Option<u32>,
+    pub max_deployments_per_user: Option<u16>,
+    pub max_hosted_bytes_per_user: Option<u64>,
+    pub quota_window: Option<i64>,
+    pub max_sponsored_lamports_per_user: Option<u64>,
+    pub claim_fee: Option<u64>,
+    pub reclaim_reward: Option<u64>,
+    pub max_hosting_period: Option<i64>,
+    pub extension_fee: Option<u64>,
+    /// `Some(None)` stops accepting token fees
+    pub fee_mint: Option<Option<Pubkey>>,
+    pub extension_token_fee: Option<u64>,
+    pub paused: Option<bool>,
+}
+
+impl DeployerConfig {
+    /// Check the limits are usable
+    pub fn validate(&self) -> Result<()> {
+        require!(self.hosting_duration > 0, ErrorCode::InvalidHostingDuration);
+        require!(
+            self.max_hosting_period >= self.hosting_duration,
+            ErrorCode::InvalidMaxHostingPeriod
+        );
+        require!(
+            self.max_program_size > 0 && self.max_program_size <= MAX_PERMITTED_PROGRAM_SIZE,
+            ErrorCode::InvalidMaxProgramSize
+        );
+        require!(
+            self.max_deployments_per_user > 0
+                && self.max_hosted_bytes_per_user > 0
+                && self.quota_window > 0
+                && self.max_sponsored_lamports_per_user > 0,
+            ErrorCode::InvalidQuota
+        );
+        Ok(())
+    }
+}
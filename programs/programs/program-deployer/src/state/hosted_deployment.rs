@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// Lifecycle of a hosted program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum DeploymentStatus {
+    /// Recorded, waiting for the buffer to be written and deployed
+    Registered,
+    /// Deployed with the deployer PDA as upgrade authority
+    Hosted,
+    /// Upgrade authority handed to the user
+    Claimed,
+    /// Closed after expiry and rent returned to the treasury
+    Reclaimed,
+}
+
+/// A program hosted on behalf of a user, stored at `[DEPLOYMENT_SEED, program]`
+#[account]
+#[derive(InitSpace)]
+pub struct HostedDeployment {
+    /// Wallet that requested the deployment and may claim it
+    pub deployer: Pubkey,
+    /// Address of the hosted program
+    pub program: Pubkey,
+    /// Buffer holding the program bytes to deploy
+    pub buffer: Pubkey,
+    /// Size of the program in bytes
+    pub program_size: u32,
+    /// SHA-256 of the hosted program bytes the API attested, updated on upgrade
+    pub program_hash: [u8; 32],
+    /// Slot the program was deployed in, or registered in until then
+    pub deploy_slot: u64,
+    /// Unix timestamp the program was deployed at, or registered at until then
+    pub hosted_at: i64,
+    /// Unix timestamp after which the program (or an undeployed buffer) may be reclaimed
+    pub expires_at: i64,
+    pub status: DeploymentStatus,
+    /// Lamports the treasury paid in rent for this program, estimated until deployed
+    pub rent_sponsored: u64,
+    /// Number of times the deployer has upgraded the program while hosted
+    pub upgrade_count: u32,
+    /// Slot of the most recent upgrade, zero if never upgraded
+    pub last_upgrade_slot: u64,
+    /// SHA-256 of the program bytes in the most recent upgrade's buffer
+    pub last_buffer_hash: [u8; 32],
+    pub bump: u8,
+}
+
+impl HostedDeployment {
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
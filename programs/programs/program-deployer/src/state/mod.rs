@@ -0,0 +1,9 @@
+pub mod deployer_config;
+pub mod hosted_deployment;
+pub mod used_nonce;
+pub mod user_quota;
+
+pub use deployer_config::*;
+pub use hosted_deployment::*;
+pub use used_nonce::*;
+pub use user_quota::*;
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+/// Marks an attestation nonce as spent, stored at `[NONCE_SEED, nonce]`
+#[account]
+#[derive(InitSpace)]
+pub struct UsedNonce {
+    pub user: Pubkey,
+    pub used_at: i64,
+}
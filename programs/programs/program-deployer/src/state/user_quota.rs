@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// A wallet's hosting usage, stored at `[USER_QUOTA_SEED, user]`
+#[account]
+#[derive(InitSpace)]
+pub struct UserQuota {
+    pub user: Pubkey,
+    /// Registered or hosted deployments not yet claimed or reclaimed
+    pub active_deployments: u16,
+    /// Program bytes across active deployments
+    pub hosted_bytes: u64,
+    /// Start of the current sponsorship window
+    pub window_start: i64,
+    /// Rent the treasury sponsored for this wallet in the current window
+    pub window_sponsored_lamports: u64,
+    pub bump: u8,
+}
+
+impl UserQuota {
+    /// Start a new window once the current one is over
+    pub fn roll_window(&mut self, now: i64, window: i64) {
+        if now.saturating_sub(self.window_start) >= window {
+            self.window_start = now;
+            self.window_sponsored_lamports = 0;
+        }
+    }
+
+    /// Release a deployment that was claimed or reclaimed
+    pub fn release(&mut self, program_size: u32) {
+        self.active_deployments = self.active_deployments.saturating_sub(1);
+        self.hosted_bytes = self.hosted_bytes.saturating_sub(u64::from(program_size));
+    }
+}
@@ -1,16 +1,967 @@
 import * as anchor from '@coral-xyz/anchor';
-import { Program } from '@coral-xyz/anchor';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  Ed25519Program,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import { createHash } from 'crypto';
+import { readFileSync } from 'fs';
 import { ProgramDeployer } from '../target/types/program_deployer';
 
+const BPF_LOADER_UPGRADEABLE = new PublicKey(
+  'BPFLoaderUpgradeab1e11111111111111111111111',
+);
+
+// Any valid program works as the hosted payload; use this one
+const PROGRAM_SO = readFileSync('target/deploy/program_deployer.so');
+const PROGRAM_HASH = createHash('sha256').update(PROGRAM_SO).digest();
+const WRITE_CHUNK_SIZE = 900;
+
+const ATTESTATION_DOMAIN = Buffer.from('program-deployer:attestation:v1');
+
+// Borsh discriminants of the program's AttestationAction
+enum AttestationAction {
+  Register = 0,
+  Upgrade = 1,
+}
+
 describe('program-deployer', () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
 
   const program = anchor.workspace.programDeployer as Program<ProgramDeployer>;
+  const admin = provider.wallet.publicKey;
+  const attestationKey = Keypair.generate();
+  let nextNonce = 0;
+
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from('config')],
+    program.programId,
+  );
+  const [treasury] = PublicKey.findProgramAddressSync(
+    [Buffer.from('treasury')],
+    program.programId,
+  );
+  const [authority] = PublicKey.findProgramAddressSync(
+    [Buffer.from('authority')],
+    program.programId,
+  );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE,
+  );
+
+  const [userQuota] = PublicKey.findProgramAddressSync(
+    [Buffer.from('quota'), admin.toBuffer()],
+    program.programId,
+  );
+
+  const deploymentAddress = (hostedProgram: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('deployment'), hostedProgram.toBuffer()],
+      program.programId,
+    )[0];
+
+  const programDataAddress = (hostedProgram: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [hostedProgram.toBuffer()],
+      BPF_LOADER_UPGRADEABLE,
+    )[0];
+
+  // Decode the events a transaction emitted through emit_cpi!
+  const cpiEvents = async (signature: string) => {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+    const keys = tx.transaction.message.getAccountKeys();
+
+    return tx.meta.innerInstructions
+      .flatMap((inner) => inner.instructions)
+      .filter((ix) => keys.get(ix.programIdIndex).equals(program.programId))
+      .map((ix) => {
+        // Skip the 8-byte event instruction tag
+        const data = anchor.utils.bytes.bs58.decode(ix.data).subarray(8);
+        return program.coder.events.decode(
+          anchor.utils.bytes.base64.encode(Buffer.from(data)),
+        );
+      })
+      .filter(Boolean);
+  };
+
+  type AttestationOptions = { signer?: Keypair; nonce?: number };
+
+  // Sign a DeployAttestation the way the API does, returning the attested
+  // params and the Ed25519Program instruction that must precede them
+  const attest = (
+    action: AttestationAction,
+    hostedProgram: PublicKey,
+    programHash: Buffer,
+    programSize: number,
+    { signer = attestationKey, nonce = nextNonce++ }: AttestationOptions = {},
+  ) => {
+    const expiry = Math.floor(Date.now() / 1000) + 300;
+
+    const attestation = Buffer.alloc(32 + 1 + 32 + 32 + 4 + 8 + 8);
+    admin.toBuffer().copy(attestation, 0);
+    attestation.writeUInt8(action, 32);
+    hostedProgram.toBuffer().copy(attestation, 33);
+    programHash.copy(attestation, 65);
+    attestation.writeUInt32LE(programSize, 97);
+    attestation.writeBigInt64LE(BigInt(expiry), 101);
+    attestation.writeBigUInt64LE(BigInt(nonce), 109);
+
+    return {
+      params: {
+        programSize,
+        programHash: Array.from(programHash),
+        attestationExpiry: new BN(expiry),
+        nonce: new BN(nonce),
+      },
+      instruction: Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signer.secretKey,
+        message: Buffer.concat([ATTESTATION_DOMAIN, attestation]),
+      }),
+    };
+  };
+
+  // Build register_deployment preceded by the API's ed25519 attestation
+  const registerDeployment = (
+    hostedProgram: PublicKey,
+    buffer: PublicKey,
+    programSize = PROGRAM_SO.length,
+    options: AttestationOptions = {},
+  ) => {
+    const { params, instruction } = attest(
+      AttestationAction.Register,
+      hostedProgram,
+      PROGRAM_HASH,
+      programSize,
+      options,
+    );
+
+    return program.methods
+      .registerDeployment({ program: hostedProgram, buffer, ...params })
+      .accountsPartial({ config, deployer: admin })
+      .preInstructions([instruction]);
+  };
+
+  // Register, fund and write PROGRAM_SO, returning the deploy_program call
+  const stageHosted = async () => {
+    const hostedProgram = Keypair.generate();
+    const buffer = Keypair.generate();
+
+    await registerDeployment(hostedProgram.publicKey, buffer.publicKey).rpc();
+
+    const deployment = deploymentAddress(hostedProgram.publicKey);
+
+    await program.methods
+      .fundBuffer()
+      .accountsPartial({ deployment, buffer: buffer.publicKey, deployer: admin })
+      .signers([buffer])
+      .rpc();
+
+    const writes = [];
+    for (let offset = 0; offset < PROGRAM_SO.length; offset += WRITE_CHUNK_SIZE) {
+      writes.push(
+        program.methods
+          .writeBuffer(offset, PROGRAM_SO.subarray(offset, offset + WRITE_CHUNK_SIZE))
+          .accountsPartial({ deployment, buffer: buffer.publicKey, deployer: admin })
+          .rpc(),
+      );
+    }
+    await Promise.all(writes);
+
+    const deploy = () =>
+      program.methods
+        .deployProgram()
+        .accountsPartial({
+          deployment,
+          hostedProgram: hostedProgram.publicKey,
+          programdata: programDataAddress(hostedProgram.publicKey),
+          buffer: buffer.publicKey,
+          deployer: admin,
+        })
+        .signers([hostedProgram]);
+
+    return { hostedProgram: hostedProgram.publicKey, deployment, deploy };
+  };
+
+  // Register, fund, write and deploy PROGRAM_SO as a hosted program
+  const deployHosted = async () => {
+    const { hostedProgram, deployment, deploy } = await stageHosted();
+    const signature = await deploy().rpc();
+
+    return { hostedProgram, deployment, signature };
+  };
+
+  // Write `bytes` into a new loader buffer the deployer funds and controls,
+  // the way `solana program write-buffer` does
+  const writeOwnBuffer = async (bytes: Buffer) => {
+    const buffer = Keypair.generate();
+    const space = 37 + bytes.length;
+
+    const initialize = Buffer.alloc(4);
+    initialize.writeUInt32LE(0, 0);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: admin,
+          newAccountPubkey: buffer.publicKey,
+          lamports:
+            await provider.connection.getMinimumBalanceForRentExemption(space),
+          space,
+          programId: BPF_LOADER_UPGRADEABLE,
+        }),
+        new TransactionInstruction({
+          programId: BPF_LOADER_UPGRADEABLE,
+          keys: [
+            { pubkey: buffer.publicKey, isSigner: false, isWritable: true },
+            { pubkey: admin, isSigner: false, isWritable: false },
+          ],
+          data: initialize,
+        }),
+      ),
+      [buffer],
+    );
+
+    const writes = [];
+    for (let offset = 0; offset < bytes.length; offset += WRITE_CHUNK_SIZE) {
+      const chunk = bytes.subarray(offset, offset + WRITE_CHUNK_SIZE);
+      const data = Buffer.alloc(16 + chunk.length);
+      data.writeUInt32LE(1, 0);
+      data.writeUInt32LE(offset, 4);
+      data.writeBigUInt64LE(BigInt(chunk.length), 8);
+      chunk.copy(data, 16);
+
+      writes.push(
+        provider.sendAndConfirm(
+          new Transaction().add(
+            new TransactionInstruction({
+              programId: BPF_LOADER_UPGRADEABLE,
+              keys: [
+                { pubkey: buffer.publicKey, isSigner: false, isWritable: true },
+                { pubkey: admin, isSigner: true, isWritable: false },
+              ],
+              data,
+            }),
+          ),
+        ),
+      );
+    }
+    await Promise.all(writes);
+
+    return buffer.publicKey;
+  };
+
+  // Make the authority PDA the buffer authority so it can sign the upgrade
+  const handOverBuffer = (buffer: PublicKey) => {
+    const data = Buffer.alloc(4);
+    data.writeUInt32LE(4, 0);
+
+    return provider.sendAndConfirm(
+      new Transaction().add(
+        new TransactionInstruction({
+          programId: BPF_LOADER_UPGRADEABLE,
+          keys: [
+            { pubkey: buffer, isSigner: false, isWritable: true },
+            { pubkey: admin, isSigner: true, isWritable: false },
+            { pubkey: authority, isSigner: false, isWritable: false },
+          ],
+          data,
+        }),
+      ),
+    );
+  };
+
+  const params = {
+    attestationKey: attestationKey.publicKey,
+    hostingDuration: new BN(7 * 24 * 60 * 60),
+    maxProgramSize: 1024 * 1024,
+    maxDeploymentsPerUser: 3,
+    maxHostedBytesPerUser: new BN(8 * 1024 * 1024),
+    quotaWindow: new BN(24 * 60 * 60),
+    maxSponsoredLamportsPerUser: new BN(100 * LAMPORTS_PER_SOL),
+    claimFee: new BN(LAMPORTS_PER_SOL / 100),
+    reclaimReward: new BN(LAMPORTS_PER_SOL / 1000),
+    maxHostingPeriod: new BN(30 * 24 * 60 * 60),
+    extensionFee: new BN(LAMPORTS_PER_SOL / 100),
+    feeMint: null,
+    extensionTokenFee: new BN(0),
+  };
+
+  const noChanges = {
+    admin: null,
+    attestationKey: null,
+    hostingDuration: null,
+    maxProgramSize: null,
+    maxDeploymentsPerUser: null,
+    maxHostedBytesPerUser: null,
+    quotaWindow: null,
+    maxSponsoredLamportsPerUser: null,
+    claimFee: null,
+    reclaimReward: null,
+    maxHostingPeriod: null,
+    extensionFee: null,
+    feeMint: null,
+    extensionTokenFee: null,
+    paused: null,
+  };
+
+  it('rejects initialize from a non-upgrade-authority', async () => {
+    const intruder = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(intruder.publicKey, 1e9),
+    );
+
+    try {
+      await program.methods
+        .initialize(params)
+        .accountsPartial({
+          admin: intruder.publicKey,
+          program: program.programId,
+          programData,
+        })
+        .signers([intruder])
+        .rpc();
+      assert.fail('initialize should be restricted to the upgrade authority');
+    } catch (err) {
+      assert.include(String(err), 'ConstraintRaw');
+    }
+  });
+
+  it('initializes the deployer config', async () => {
+    await program.methods
+      .initialize(params)
+      .accountsPartial({ admin, program: program.programId, programData })
+      .rpc();
+
+    const account = await program.account.deployerConfig.fetch(config);
+    assert.ok(account.admin.equals(admin));
+    assert.ok(account.treasury.equals(treasury));
+    assert.equal(account.hostingDuration.toNumber(), 7 * 24 * 60 * 60);
+    assert.equal(account.maxProgramSize, params.maxProgramSize);
+    assert.equal(account.maxDeploymentsPerUser, 3);
+    assert.isFalse(account.paused);
+  });
+
+  it('lets the admin update the config', async () => {
+    await program.methods
+      .updateConfig({ ...noChanges, paused: true, maxDeploymentsPerUser: 8 })
+      .accountsPartial({ config, admin })
+      .rpc();
+
+    const account = await program.account.deployerConfig.fetch(config);
+    assert.isTrue(account.paused);
+    assert.equal(account.maxDeploymentsPerUser, 8);
+
+    await program.methods
+      .updateConfig({ ...noChanges, paused: false })
+      .accountsPartial({ config, admin })
+      .rpc();
+  });
+
+  it('rejects invalid config values', async () => {
+    try {
+      await program.methods
+        .updateConfig({ ...noChanges, hostingDuration: new BN(0) })
+        .accountsPartial({ config, admin })
+        .rpc();
+      assert.fail('zero hosting duration should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'InvalidHostingDuration');
+    }
+  });
+
+  it('registers a hosted deployment', async () => {
+    const hostedProgram = Keypair.generate().publicKey;
+    const buffer = Keypair.generate().publicKey;
+    const [deployment] = PublicKey.findProgramAddressSync(
+      [Buffer.from('deployment'), hostedProgram.toBuffer()],
+      program.programId,
+    );
+
+    await registerDeployment(hostedProgram, buffer, 200_000).rpc();
+
+    const account = await program.account.hostedDeployment.fetch(deployment);
+    assert.ok(account.deployer.equals(admin));
+    assert.ok(account.program.equals(hostedProgram));
+    assert.ok(account.buffer.equals(buffer));
+    assert.deepEqual(account.status, { registered: {} });
+    assert.isAbove(account.rentSponsored.toNumber(), 0);
+    assert.isAbove(account.expiresAt.toNumber(), Date.now() / 1000);
+  });
+
+  it('rejects registrations without a valid attestation', async () => {
+    try {
+      await program.methods
+        .registerDeployment({
+          program: Keypair.generate().publicKey,
+          buffer: Keypair.generate().publicKey,
+          programSize: PROGRAM_SO.length,
+          programHash: Array.from(PROGRAM_HASH),
+          attestationExpiry: new BN(Math.floor(Date.now() / 1000) + 300),
+          nonce: new BN(nextNonce++),
+        })
+        .accountsPartial({ config, deployer: admin })
+        .rpc();
+      assert.fail('registration without an attestation should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'MissingAttestation');
+    }
+
+    try {
+      await registerDeployment(
+        Keypair.generate().publicKey,
+        Keypair.generate().publicKey,
+        PROGRAM_SO.length,
+        { signer: Keypair.generate() },
+      ).rpc();
+      assert.fail('attestations from other keys should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'InvalidAttestation');
+    }
+  });
+
+  it('rejects a replayed attestation nonce', async () => {
+    const nonce = nextNonce++;
+    await registerDeployment(
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      10_000,
+      { nonce },
+    ).rpc();
+
+    try {
+      await registerDeployment(
+        Keypair.generate().publicKey,
+        Keypair.generate().publicKey,
+        10_000,
+        { nonce },
+      ).rpc();
+      assert.fail('a nonce can only be used once');
+    } catch (err) {
+      assert.include(String(err), 'already in use');
+    }
+  });
+
+  it('deploys a hosted program with treasury-sponsored rent', async () => {
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: admin,
+          toPubkey: treasury,
+          lamports: 50 * LAMPORTS_PER_SOL,
+        }),
+      ),
+    );
+
+    const deployerBalance = await provider.connection.getBalance(admin);
+    const { hostedProgram, deployment, signature } = await deployHosted();
+
+    const [deployed] = await cpiEvents(signature);
+    assert.equal(deployed.name, 'programDeployed');
+    assert.ok(deployed.data.program.equals(hostedProgram));
+
+    const programAccount = await provider.connection.getAccountInfo(hostedProgram);
+    assert.isTrue(programAccount.executable);
+
+    const account = await program.account.hostedDeployment.fetch(deployment);
+    assert.deepEqual(account.status, { hosted: {} });
+    assert.isAbove(account.deploySlot.toNumber(), 0);
+
+    // The deployer pays fees, the record's rent and the program account, which
+    // the loader can never close; the treasury pays for the programdata
+    const programRent = await provider.connection.getMinimumBalanceForRentExemption(36);
+    assert.equal(programAccount.lamports, programRent);
+    assert.equal(
+      account.rentSponsored.toNumber(),
+      await provider.connection.getBalance(programDataAddress(hostedProgram)),
+    );
+    const spent = deployerBalance - (await provider.connection.getBalance(admin));
+    assert.isBelow(spent, account.rentSponsored.toNumber());
+
+    const programData = await provider.connection.getParsedAccountInfo(
+      programDataAddress(hostedProgram),
+    );
+    assert.equal(
+      (programData.value.data as any).parsed.info.authority,
+      authority.toBase58(),
+    );
+  });
+
+  it('lets the deployer claim upgrade authority', async () => {
+    const { hostedProgram, deployment } = await deployHosted();
+    const before = await program.account.hostedDeployment.fetch(deployment);
+    const { activeDeployments: activeBefore } =
+      await program.account.userQuota.fetch(userQuota);
+    const treasuryBalance = await provider.connection.getBalance(treasury);
+
+    await program.methods
+      .claimAuthority()
+      .accountsPartial({
+        deployment,
+        hostedProgram,
+        programdata: programDataAddress(hostedProgram),
+        deployer: admin,
+      })
+      .rpc();
+
+    const account = await program.account.hostedDeployment.fetch(deployment);
+    assert.deepEqual(account.status, { claimed: {} });
+
+    const quota = await program.account.userQuota.fetch(userQuota);
+    assert.equal(quota.activeDeployments, activeBefore - 1);
+
+    assert.equal(
+      (await provider.connection.getBalance(treasury)) - treasuryBalance,
+      before.rentSponsored.add(params.claimFee).toNumber(),
+    );
+
+    const programData = await provider.connection.getParsedAccountInfo(
+      programDataAddress(hostedProgram),
+    );
+    assert.equal(
+      (programData.value.data as any).parsed.info.authority,
+      admin.toBase58(),
+    );
+
+    try {
+      await program.methods
+        .claimAuthority()
+        .accountsPartial({
+          deployment,
+          hostedProgram,
+          programdata: programDataAddress(hostedProgram),
+          deployer: admin,
+        })
+        .rpc();
+      assert.fail('a program can only be claimed once');
+    } catch (err) {
+      assert.include(String(err), 'AlreadyClaimed');
+    }
+  });
+
+  it('extends hosting for a SOL fee up to the maximum period', async () => {
+    const { deployment } = await deployHosted();
+    const before = await program.account.hostedDeployment.fetch(deployment);
+    const treasuryBalance = await provider.connection.getBalance(treasury);
+
+    const extend = (days: number) =>
+      program.methods
+        .extendHosting(days)
+        .accountsPartial({
+          deployment,
+          payer: admin,
+          feeMint: null,
+          payerTokenAccount: null,
+          treasuryTokenAccount: null,
+          tokenProgram: null,
+        })
+        .rpc();
+
+    await extend(3);
+
+    const account = await program.account.hostedDeployment.fetch(deployment);
+    assert.equal(
+      account.expiresAt.sub(before.expiresAt).toNumber(),
+      3 * 24 * 60 * 60,
+    );
+    assert.equal(
+      (await provider.connection.getBalance(treasury)) - treasuryBalance,
+      params.extensionFee.muln(3).toNumber(),
+    );
+
+    try {
+      // 7 + 3 + 21 days is past the 30-day maximum
+      await extend(21);
+      assert.fail('extensions past the maximum hosting period should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'HostingPeriodExceeded');
+    }
+  });
+
+  it('upgrades a hosted program from the deployer\'s buffer', async () => {
+    const { hostedProgram, deployment } = await deployHosted();
+
+    type UpgradeOptions = AttestationOptions & {
+      attested?: Buffer;
+      action?: AttestationAction;
+      attestedProgram?: PublicKey;
+    };
+
+    // Attest an upgrade of this program to `bytes` unless told otherwise, the
+    // way the API would
+    const upgrade = (
+      buffer: PublicKey,
+      bytes: Buffer,
+      {
+        attested = bytes,
+        action = AttestationAction.Upgrade,
+        attestedProgram = hostedProgram,
+        ...options
+      }: UpgradeOptions = {},
+    ) => {
+      const { params, instruction } = attest(
+        action,
+        attestedProgram,
+        createHash('sha256').update(attested).digest(),
+        attested.length,
+        options,
+      );
+
+      return program.methods
+        .upgradeHosted(params)
+        .accountsPartial({
+          deployment,
+          hostedProgram,
+          programdata: programDataAddress(hostedProgram),
+          buffer,
+          deployer: admin,
+        })
+        .preInstructions([instruction])
+        .rpc();
+    };
+
+    try {
+      // The programdata account only has room for the registered size
+      const oversized = Buffer.alloc(PROGRAM_SO.length + 1);
+      await upgrade(await writeOwnBuffer(oversized), oversized);
+      assert.fail('upgrades larger than the hosted program should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'UpgradeTooLarge');
+    }
+
+    const buffer = await writeOwnBuffer(PROGRAM_SO);
+    await handOverBuffer(buffer);
+
+    try {
+      await upgrade(buffer, PROGRAM_SO, { attested: Buffer.alloc(PROGRAM_SO.length) });
+      assert.fail('upgrades to unattested bytes should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'ProgramHashMismatch');
+    }
+
+    try {
+      await upgrade(buffer, PROGRAM_SO, { signer: Keypair.generate() });
+      assert.fail('attestations from other keys should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'InvalidAttestation');
+    }
+
+    try {
+      await upgrade(buffer, PROGRAM_SO, { action: AttestationAction.Register });
+      assert.fail('register attestations should not authorize upgrades');
+    } catch (err) {
+      assert.include(String(err), 'InvalidAttestation');
+    }
+
+    try {
+      await upgrade(buffer, PROGRAM_SO, { attestedProgram: Keypair.generate().publicKey });
+      assert.fail('attestations for other programs should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'InvalidAttestation');
+    }
+
+    await program.methods
+      .updateConfig({ ...noChanges, paused: true })
+      .accountsPartial({ config, admin })
+      .rpc();
+    try {
+      await upgrade(buffer, PROGRAM_SO);
+      assert.fail('upgrades should be rejected while paused');
+    } catch (err) {
+      assert.include(String(err), 'Paused');
+    } finally {
+      await program.methods
+        .updateConfig({ ...noChanges, paused: false })
+        .accountsPartial({ config, admin })
+        .rpc();
+    }
+
+    const signature = await upgrade(buffer, PROGRAM_SO);
+
+    const [upgraded] = await cpiEvents(signature);
+    assert.equal(upgraded.name, 'hostedProgramUpgraded');
+    assert.equal(upgraded.data.upgradeCount, 1);
+
+    const account = await program.account.hostedDeployment.fetch(deployment);
+    assert.equal(account.upgradeCount, 1);
+    assert.isAbove(account.lastUpgradeSlot.toNumber(), account.deploySlot.toNumber());
+    assert.deepEqual(account.lastBufferHash, Array.from(PROGRAM_HASH));
+    assert.deepEqual(account.programHash, Array.from(PROGRAM_HASH));
+
+    // The spent buffer's lamports go back to the deployer who funded it
+    assert.isNull(await provider.connection.getAccountInfo(buffer));
+
+    // Once claimed, upgrades go through the loader directly
+    await program.methods
+      .claimAuthority()
+      .accountsPartial({
+        deployment,
+        hostedProgram,
+        programdata: programDataAddress(hostedProgram),
+        deployer: admin,
+      })
+      .rpc();
+
+    try {
+      const bytes = PROGRAM_SO.subarray(0, 1024);
+      await upgrade(await writeOwnBuffer(bytes), bytes);
+      assert.fail('claimed programs cannot be upgraded through the deployer');
+    } catch (err) {
+      assert.include(String(err), 'AlreadyClaimed');
+    }
+  });
+
+  it('refuses to deploy while paused or after the registration lapsed', async () => {
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: new BN(1) })
+      .accountsPartial({ config, admin })
+      .rpc();
+    const { deploy } = await stageHosted();
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: params.hostingDuration, paused: true })
+      .accountsPartial({ config, admin })
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    try {
+      await deploy().rpc();
+      assert.fail('deployments should be rejected while paused');
+    } catch (err) {
+      assert.include(String(err), 'Paused');
+    } finally {
+      await program.methods
+        .updateConfig({ ...noChanges, paused: false })
+        .accountsPartial({ config, admin })
+        .rpc();
+    }
+
+    try {
+      // Deploying would otherwise restart the hosting period
+      await deploy().rpc();
+      assert.fail('lapsed registrations should not be deployable');
+    } catch (err) {
+      assert.include(String(err), 'DeploymentExpired');
+    }
+  });
+
+  it('reclaims expired deployments and rewards the cranker', async () => {
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: new BN(1) })
+      .accountsPartial({ config, admin })
+      .rpc();
+
+    const { hostedProgram, deployment } = await deployHosted();
+
+    // A registration whose buffer was funded but never deployed
+    const abandonedProgram = Keypair.generate().publicKey;
+    const buffer = Keypair.generate();
+    await registerDeployment(abandonedProgram, buffer.publicKey, 10_000).rpc();
+    const abandoned = deploymentAddress(abandonedProgram);
+    await program.methods
+      .fundBuffer()
+      .accountsPartial({ deployment: abandoned, buffer: buffer.publicKey, deployer: admin })
+      .signers([buffer])
+      .rpc();
+
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: params.hostingDuration })
+      .accountsPartial({ config, admin })
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const cranker = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(cranker.publicKey, LAMPORTS_PER_SOL),
+    );
+
+    await program.methods
+      .reclaimExpired()
+      .accountsPartial({ cranker: cranker.publicKey })
+      .remainingAccounts(
+        [
+          deployment,
+          userQuota,
+          hostedProgram,
+          programDataAddress(hostedProgram),
+          abandoned,
+          userQuota,
+          abandonedProgram,
+          buffer.publicKey,
+        ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      )
+      .signers([cranker])
+      .rpc();
+
+    for (const address of [deployment, abandoned]) {
+      const account = await program.account.hostedDeployment.fetch(address);
+      assert.deepEqual(account.status, { reclaimed: {} });
+    }
+    assert.isNull(
+      await provider.connection.getAccountInfo(programDataAddress(hostedProgram)),
+    );
+    assert.isNull(await provider.connection.getAccountInfo(buffer.publicKey));
+
+    // Two rewards, less the transaction fee
+    const earned =
+      (await provider.connection.getBalance(cranker.publicKey)) - LAMPORTS_PER_SOL;
+    assert.isAbove(earned, params.reclaimReward.toNumber());
+  });
+
+  it('does not reward reclaiming unfunded registrations', async () => {
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: new BN(1) })
+      .accountsPartial({ config, admin })
+      .rpc();
+
+    // Registered but never funded, so closing it returns nothing
+    const unfundedProgram = Keypair.generate().publicKey;
+    const buffer = Keypair.generate().publicKey;
+    await registerDeployment(unfundedProgram, buffer, 10_000).rpc();
+    const unfunded = deploymentAddress(unfundedProgram);
+
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: params.hostingDuration })
+      .accountsPartial({ config, admin })
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const cranker = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(cranker.publicKey, LAMPORTS_PER_SOL),
+    );
+    const treasuryBalance = await provider.connection.getBalance(treasury);
+
+    await program.methods
+      .reclaimExpired()
+      .accountsPartial({ cranker: cranker.publicKey })
+      .remainingAccounts(
+        [unfunded, userQuota, unfundedProgram, buffer].map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        })),
+      )
+      .signers([cranker])
+      .rpc();
+
+    const account = await program.account.hostedDeployment.fetch(unfunded);
+    assert.deepEqual(account.status, { reclaimed: {} });
+
+    // The cranker only paid the transaction fee and the treasury paid nothing
+    assert.isBelow(
+      await provider.connection.getBalance(cranker.publicKey),
+      LAMPORTS_PER_SOL,
+    );
+    assert.equal(await provider.connection.getBalance(treasury), treasuryBalance);
+  });
+
+  it('skips deployments whose buffer the authority PDA does not control', async () => {
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: new BN(1) })
+      .accountsPartial({ config, admin })
+      .rpc();
+
+    // Registered against a buffer the deployer still controls
+    const foreignProgram = Keypair.generate().publicKey;
+    const foreignBuffer = await writeOwnBuffer(PROGRAM_SO.subarray(0, 100));
+    await registerDeployment(foreignProgram, foreignBuffer, 10_000).rpc();
+    const foreign = deploymentAddress(foreignProgram);
+
+    const unfundedProgram = Keypair.generate().publicKey;
+    const unfundedBuffer = Keypair.generate().publicKey;
+    await registerDeployment(unfundedProgram, unfundedBuffer, 10_000).rpc();
+    const unfunded = deploymentAddress(unfundedProgram);
+
+    await program.methods
+      .updateConfig({ ...noChanges, hostingDuration: params.hostingDuration })
+      .accountsPartial({ config, admin })
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    await program.methods
+      .reclaimExpired()
+      .accountsPartial({ cranker: admin })
+      .remainingAccounts(
+        [
+          foreign,
+          userQuota,
+          foreignProgram,
+          foreignBuffer,
+          unfunded,
+          userQuota,
+          unfundedProgram,
+          unfundedBuffer,
+        ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      )
+      .rpc();
+
+    const skipped = await program.account.hostedDeployment.fetch(foreign);
+    assert.deepEqual(skipped.status, { registered: {} });
+    assert.isNotNull(await provider.connection.getAccountInfo(foreignBuffer));
+
+    const reclaimed = await program.account.hostedDeployment.fetch(unfunded);
+    assert.deepEqual(reclaimed.status, { reclaimed: {} });
+  });
+
+  it('enforces the per-user deployment quota', async () => {
+    const { activeDeployments } = await program.account.userQuota.fetch(userQuota);
+    await program.methods
+      .updateConfig({ ...noChanges, maxDeploymentsPerUser: activeDeployments })
+      .accountsPartial({ config, admin })
+      .rpc();
+
+    try {
+      await registerDeployment(
+        Keypair.generate().publicKey,
+        Keypair.generate().publicKey,
+        10_000,
+      ).rpc();
+      assert.fail('registrations beyond the quota should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'TooManyDeployments');
+    } finally {
+      await program.methods
+        .updateConfig({ ...noChanges, maxDeploymentsPerUser: 8 })
+        .accountsPartial({ config, admin })
+        .rpc();
+    }
+  });
+
+  it('rejects programs over the size limit', async () => {
+    try {
+      await registerDeployment(
+        Keypair.generate().publicKey,
+        Keypair.generate().publicKey,
+        params.maxProgramSize + 1,
+      ).rpc();
+      assert.fail('oversized programs should be rejected');
+    } catch (err) {
+      assert.include(String(err), 'ProgramTooLarge');
+    }
+  });
+
+  it('rejects updates from anyone but the admin', async () => {
+    const intruder = Keypair.generate();
 
-  it('Is initialized!', async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log('Your transaction signature', tx);
+    try {
+      await program.methods
+        .updateConfig({ ...noChanges, paused: true })
+        .accountsPartial({ config, admin: intruder.publicKey })
+        .signers([intruder])
+        .rpc();
+      assert.fail('update_config should be admin-only');
+    } catch (err) {
+      assert.include(String(err), 'Unauthorized');
+    }
   });
 });